@@ -1,4 +1,9 @@
-mod fpl_players {
+// The entry points most callers need, re-exported at the top of the crate
+pub use fpl_data;
+pub use fpl_data::fpl_client::FplClient;
+pub use fpl_dataset::{FplDataset, FplDatasetError};
+
+pub mod fpl_players {
     use std::collections::HashMap;
 
     use crate::fpl_positions;
@@ -120,11 +125,31 @@ mod fpl_players {
 
     #[derive(Debug, PartialEq)]
     pub struct FplPlayerExpectations {
-        pub expected_goals: f64,
-        pub expected_assists: f64,
-        pub expected_goal_involvements: f64,
-        pub expected_goals_conceded: f64,
-        pub expected_clean_sheets: f64,
+        pub expected_goals: Option<f64>,
+        pub expected_assists: Option<f64>,
+        pub expected_goal_involvements: Option<f64>,
+        pub expected_goals_conceded: Option<f64>,
+    }
+
+    // How the game rates the player's recent returns and value for money. Any of these
+    // can be blank in the API, and are None then
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerForm {
+        pub form: Option<f64>,
+        pub points_per_game: Option<f64>,
+        pub selected_by_percent: Option<f64>,
+        pub value_form: Option<f64>,
+        pub value_season: Option<f64>,
+        pub expected_points_this: Option<f64>,
+        pub expected_points_next: Option<f64>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerIct {
+        pub influence: Option<f64>,
+        pub creativity: Option<f64>,
+        pub threat: Option<f64>,
+        pub ict_index: Option<f64>,
     }
 
     #[derive(Debug, PartialEq)]
@@ -137,11 +162,15 @@ mod fpl_players {
     #[derive(Debug, PartialEq)]
     pub struct FplPlayer {
         pub id: u32,
+        pub code: u32, // Stays the same from season to season, unlike the id
+        pub team_id: u64,
         pub name: FplPlayerName,
         pub position: fpl_positions::Position,
         pub stats: FplPlayerStats,
         pub stats_per_90: FplPlayerStatsPer90,
         pub expected_stats: FplPlayerExpectations,
+        pub form: FplPlayerForm,
+        pub ict: FplPlayerIct,
         pub points_record: FplPlayerPointsRecord,
     }
 
@@ -151,6 +180,7 @@ mod fpl_players {
         }
     }
 
+    #[derive(Default)]
     pub struct FplPlayerList {
         pub player_list: HashMap<u32, FplPlayer>,
     }
@@ -186,7 +216,7 @@ mod fpl_players {
     }
 }
 
-mod fpl_teams {
+pub mod fpl_teams {
     #[derive(Debug)]
     pub struct FplTeamTableData {
         pub played: u32,
@@ -210,6 +240,7 @@ mod fpl_teams {
     #[derive(Debug)]
     pub struct FplTeam {
         pub id: u64,
+        pub code: u64, // Stays the same from season to season, unlike the id
         pub name: String,
         pub short_name: String,
         pub table_data: FplTeamTableData,
@@ -217,7 +248,7 @@ mod fpl_teams {
     }
 }
 
-mod fpl_positions {
+pub mod fpl_positions {
 
     use std::convert::TryFrom;
 
@@ -254,17 +285,30 @@ mod fpl_positions {
     }
 }
 
-mod fpl_match_stats {
+pub mod fpl_match_stats {
 
     use crate::{
-        fpl_fixtures::player_points, fpl_players::{self, FplPlayer, FplPlayerList}, fpl_positions
+        fpl_conversions,
+        fpl_fixtures::{breakdown_total, player_points, player_points_breakdown},
+        fpl_players::{self, FplPlayer, FplPlayerList},
+        fpl_positions,
+    };
+    use fpl_data::fpl_data::FplApiScoringValue;
+    use serde::{Deserialize, Serialize};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        hash::Hash,
+        path::Path,
     };
-    use std::{collections::HashMap, hash::Hash};
 
-    #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    // Serialized with the API's identifiers, e.g. "goals_scored"
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    #[serde(rename_all = "snake_case")]
     pub enum MatchStatistic {
         GoalsScored,
         Assists,
+        CleanSheets,
+        GoalsConceded,
         OwnGoals,
         PenaltiesSaved,
         PenaltiesMissed,
@@ -274,6 +318,8 @@ mod fpl_match_stats {
         Bonus,
         Bps,
         Minutes,
+        DefensiveContribution,
+        ExpectedGoals, // In hundredths of a goal
     }
 
     impl MatchStatistic {
@@ -281,6 +327,8 @@ mod fpl_match_stats {
             match identifier {
                 "goals_scored" => Ok(MatchStatistic::GoalsScored),
                 "assists" => Ok(MatchStatistic::Assists),
+                "clean_sheets" => Ok(MatchStatistic::CleanSheets),
+                "goals_conceded" => Ok(MatchStatistic::GoalsConceded),
                 "own_goals" => Ok(MatchStatistic::OwnGoals),
                 "penalties_saved" => Ok(MatchStatistic::PenaltiesSaved),
                 "penalties_missed" => Ok(MatchStatistic::PenaltiesMissed),
@@ -290,9 +338,16 @@ mod fpl_match_stats {
                 "bonus" => Ok(MatchStatistic::Bonus),
                 "bps" => Ok(MatchStatistic::Bps),
                 "minutes" => Ok(MatchStatistic::Minutes),
+                "defensive_contribution" => Ok(MatchStatistic::DefensiveContribution),
+                "expected_goals" => Ok(MatchStatistic::ExpectedGoals),
                 _ => Err(format!("Could not convert statistic")),
             }
         }
+
+        // Only goalkeepers can record these, so a single value in the game settings is theirs
+        pub fn goalkeeper_only(&self) -> bool {
+            matches!(self, MatchStatistic::Saves | MatchStatistic::PenaltiesSaved)
+        }
     }
 
     type PlayerToStatisticMap = HashMap<u32, MatchStatisticValueMap>;
@@ -303,14 +358,14 @@ mod fpl_match_stats {
     pub type MatchStatisticValueIter =
         std::collections::hash_map::Iter<'static, MatchStatistic, i32>;
 
-    #[derive(Debug)]
+    #[derive(Debug, Default)]
     pub struct MatchStatisticMap {
         match_stats: HashMap<u32, MatchStatisticValueMap>,
     }
 
     #[derive(Debug)]
-    struct PlayerPointPair<'a> {
-        pub player: &'a fpl_players::FplPlayer,
+    struct PlayerPointPair {
+        pub player_id: u32,
         pub bonus_points: i32,
     }
 
@@ -322,13 +377,12 @@ mod fpl_match_stats {
         }
 
         // Function to extract the bonus points from the match stat and form a simplified structure
-        fn get_bonus_points_per_player<'a>(
+        fn get_bonus_points_per_player(
             player_id: &u32,
             stat_player: &MatchStatisticValueMap,
-            player_list: &'a fpl_players::FplPlayerList,
-        ) -> PlayerPointPair<'a> {
+        ) -> PlayerPointPair {
             PlayerPointPair {
-                player: player_list.get_player(player_id).unwrap(),
+                player_id: *player_id,
                 bonus_points: stat_player
                     .get(&MatchStatistic::Bps)
                     .or(Some(&0))
@@ -337,11 +391,11 @@ mod fpl_match_stats {
             }
         }
 
-        fn rank_players_by_bonus_points<'a>(
-            player_points_in_match: Vec<PlayerPointPair<'a>>,
-        ) -> Vec<(&'a FplPlayer, usize)> {
+        fn rank_players_by_bonus_points(
+            player_points_in_match: Vec<PlayerPointPair>,
+        ) -> Vec<(u32, usize)> {
             // Rank the points
-            let mut rankings: Vec<(&'a FplPlayer, usize)> = Vec::new();
+            let mut rankings: Vec<(u32, usize)> = Vec::new();
             let mut current_rank: usize = 1;
             let mut previous_points = None;
 
@@ -349,59 +403,109 @@ mod fpl_match_stats {
                 if Some(player.bonus_points) != previous_points {
                     current_rank = index + 1;
                 }
-                rankings.push((player.player, current_rank));
+                rankings.push((player.player_id, current_rank));
                 previous_points = Some(player.bonus_points);
             }
 
             rankings
         }
 
-        fn player_points_from_ranking<'a>(
-            player_rankings: Vec<(&'a FplPlayer, usize)>,
-        ) -> Vec<(&'a FplPlayer, i32)> {
+        fn player_points_from_ranking(
+            player_rankings: Vec<(u32, usize)>,
+            rules: &ScoringRules,
+        ) -> Vec<(u32, i32)> {
             player_rankings
                 .into_iter()
-                .map(|(player, rank)| -> (&FplPlayer, i32) {
-                    (player, bonus_points_rank_to_points(rank))
-                })
+                .map(|(player_id, rank)| (player_id, bonus_points_rank_to_points(rules, rank)))
                 .collect()
         }
 
+        // Scoring needs each player's position, so every player in the stats must be in the list
+        fn find_player(
+            player_list: &fpl_players::FplPlayerList,
+            player_id: u32,
+        ) -> Result<&FplPlayer, String> {
+            player_list
+                .get_player(&player_id)
+                .ok_or_else(|| format!("Player {} has match statistics but is not in the player list", player_id))
+        }
+
         pub fn get_player_points_from_stats<'a>(
             &mut self,
             player_list: &'a fpl_players::FplPlayerList,
-        ) -> Vec<(&'a FplPlayer, i32)> {
-            // BONUS POINTS
+            rules: &ScoringRules,
+        ) -> Result<Vec<(&'a FplPlayer, i32)>, String> {
+            self.update_bonus_points(rules);
+
+            // Now sum up the FPL points
+            let sum_fpl_points = |(&player_id, match_stats): (&u32, &MatchStatisticValueMap)| {
+                let player = Self::find_player(player_list, player_id)?;
+                Ok((player, player_points(rules, match_stats, player)))
+            };
+            self.match_stats.iter().map(sum_fpl_points).collect()
+        }
+
+        // The same points itemised by statistic, one breakdown per player ordered by id
+        pub fn get_player_points_breakdown(
+            &mut self,
+            fixture_id: u32,
+            player_list: &fpl_players::FplPlayerList,
+            rules: &ScoringRules,
+        ) -> Result<Vec<PointsBreakdown>, String> {
+            self.update_bonus_points(rules);
+
+            let mut breakdowns = self
+                .match_stats
+                .iter()
+                .map(|(&player_id, match_stats)| {
+                    let player = Self::find_player(player_list, player_id)?;
+                    let stats = player_points_breakdown(rules, match_stats, player);
+                    Ok(PointsBreakdown {
+                        fixture: fixture_id,
+                        element: player_id,
+                        total_points: breakdown_total(&stats),
+                        stats,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            breakdowns.sort_by_key(|breakdown| breakdown.element);
+            Ok(breakdowns)
+        }
+
+        fn update_bonus_points(&mut self, rules: &ScoringRules) {
+            // Without any bps there is nothing to rank, so keep whatever bonus was recorded
+            let has_bps = self
+                .match_stats
+                .values()
+                .any(|stats| stats.contains_key(&MatchStatistic::Bps));
+            if has_bps {
+                self.award_bonus_points(rules);
+            }
+        }
+
+        fn award_bonus_points(&mut self, rules: &ScoringRules) {
             // Extract a list of players and bonus points
             let mut player_bonus_points_in_match: Vec<PlayerPointPair> = self
                 .match_stats
                 .iter()
                 .map(|stats: (&u32, &HashMap<MatchStatistic, i32>)| {
-                    Self::get_bonus_points_per_player(stats.0, stats.1, player_list)
+                    Self::get_bonus_points_per_player(stats.0, stats.1)
                 })
                 .collect();
 
             // Sort the players by the most points
-            player_bonus_points_in_match.sort_by_key(|p| p.bonus_points);
+            player_bonus_points_in_match.sort_by_key(|p| std::cmp::Reverse(p.bonus_points));
 
             let rankings = Self::rank_players_by_bonus_points(player_bonus_points_in_match);
 
-            let bonus_points = Self::player_points_from_ranking(rankings);
+            let bonus_points = Self::player_points_from_ranking(rankings, rules);
 
-            let set_stat_value = |&(player, points): &(&FplPlayer, i32)| {
-                self.match_stats.get_mut(&player.id).unwrap().insert(MatchStatistic::Bonus, points);
-            };
-            bonus_points.iter().for_each(set_stat_value);
-
-            // FINISHED THE BONUS POINTS
-
-            // Now sum up the FPL points
-            let sum_fpl_points =
-                |(&player_id, match_stats): (&u32, &MatchStatisticValueMap)| -> (&'a FplPlayer, i32) {
-                    let player = player_list.get_player(&player_id).unwrap();
-                    (player, player_points(&match_stats, &player))
-                };
-            self.match_stats.iter().map(sum_fpl_points).collect()
+            for (player_id, points) in bonus_points {
+                self.match_stats
+                    .entry(player_id)
+                    .or_default()
+                    .insert(MatchStatistic::Bonus, points);
+            }
         }
 
         // Get a list of all of the players in the match
@@ -411,7 +515,7 @@ mod fpl_match_stats {
 
             match_players
                 .into_iter()
-                .map(|player_id| players.get_player(player_id).unwrap())
+                .filter_map(|player_id| players.get_player(player_id))
                 .collect()
         }
 
@@ -426,76 +530,458 @@ mod fpl_match_stats {
                 .or_default()
                 .insert(identifier, value)
         }
+
+        // Take any statistics this map is missing from another one, keeping the values it has
+        pub fn add_missing_statistics(&mut self, other: &MatchStatisticMap) {
+            for (&element, stats) in &other.match_stats {
+                let player_stats = self.match_stats.entry(element).or_default();
+                for (&identifier, &value) in stats {
+                    player_stats.entry(identifier).or_insert(value);
+                }
+            }
+        }
+    }
+
+    // What one statistic was worth to a player, like an entry in the stats of the API's explain
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct StatisticPoints {
+        pub identifier: MatchStatistic,
+        pub value: i32,
+        pub points: i32,
+        pub rule: String, // The rule that applied, in words
+    }
+
+    // A player's points in one fixture, shaped like an entry of explain in event/{id}/live
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct PointsBreakdown {
+        pub fixture: u32,
+        pub element: u32, // Player id
+        pub stats: Vec<StatisticPoints>,
+        pub total_points: i32,
+    }
+
+    impl PointsBreakdown {
+        pub fn statistic(&self, statistic: MatchStatistic) -> Option<&StatisticPoints> {
+            self.stats.iter().find(|stat| stat.identifier == statistic)
+        }
+    }
+
+    // Points for one of a statistic, by the position of the player
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct PositionPoints {
+        pub goalkeeper: i32,
+        pub defender: i32,
+        pub midfielder: i32,
+        pub forward: i32,
+    }
+
+    impl PositionPoints {
+        pub fn all(points: i32) -> Self {
+            PositionPoints {
+                goalkeeper: points,
+                defender: points,
+                midfielder: points,
+                forward: points,
+            }
+        }
+
+        pub fn goalkeeper(points: i32) -> Self {
+            PositionPoints {
+                goalkeeper: points,
+                ..PositionPoints::all(0)
+            }
+        }
+
+        pub fn for_position(&self, position: &fpl_positions::Position) -> i32 {
+            match position {
+                fpl_positions::Position::GK => self.goalkeeper,
+                fpl_positions::Position::DEF => self.defender,
+                fpl_positions::Position::MID => self.midfielder,
+                fpl_positions::Position::FWD => self.forward,
+            }
+        }
+    }
+
+    // The points each statistic is worth. The defaults are the official FPL scoring; the game
+    // settings in bootstrap-static can override them.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ScoringRules {
+        pub points: HashMap<MatchStatistic, PositionPoints>,
+        pub saves_per_point: i32,
+        pub goals_conceded_per_point: i32,
+        pub expected_goals_per_point: i32, // In hundredths of a goal, like the statistic
+        pub long_play_minutes: i32, // Playing at least this long earns the long play points
+        pub long_play_points: i32,
+        pub short_play_points: i32,
+        pub clean_sheet_minutes: i32, // A clean sheet only counts after this long on the pitch
+        // Defensive actions a player needs in a match, by position, before their defensive
+        // contribution points count
+        pub defensive_contribution_threshold: PositionPoints,
+        pub bonus_by_rank: Vec<i32>, // Bonus points for first, second, ... on bps
+    }
+
+    // Seasons with built-in scoring rules, oldest first. The last is the default.
+    pub const SCORING_SEASONS: [&str; 4] = ["2022-23", "2023-24", "2024-25", "2025-26"];
+
+    impl Default for ScoringRules {
+        fn default() -> Self {
+            ScoringRules::for_season(SCORING_SEASONS[SCORING_SEASONS.len() - 1])
+                .expect("No scoring rules for the latest season")
+        }
+    }
+
+    impl ScoringRules {
+        // The official scoring for a season, named like "2024-25"
+        pub fn for_season(season: &str) -> Option<ScoringRules> {
+            let mut rules = ScoringRules::season_2024_25();
+            match season {
+                // A goalkeeper's goal was worth the same as a defender's
+                "2022-23" | "2023-24" => {
+                    rules.points.insert(
+                        MatchStatistic::GoalsScored,
+                        PositionPoints {
+                            goalkeeper: 6,
+                            defender: 6,
+                            midfielder: 5,
+                            forward: 4,
+                        },
+                    );
+                }
+                "2024-25" => (),
+                // Defensive contributions score from 2025/26. Assists are credited more widely too
+                // (winning a penalty or free kick, a saved shot that is put away), which changes
+                // the assists recorded rather than what each one is worth.
+                "2025-26" => {
+                    rules.points.insert(
+                        MatchStatistic::DefensiveContribution,
+                        PositionPoints {
+                            goalkeeper: 0,
+                            ..PositionPoints::all(2)
+                        },
+                    );
+                    rules.defensive_contribution_threshold = PositionPoints {
+                        goalkeeper: 0,
+                        defender: 10,
+                        midfielder: 12,
+                        forward: 12,
+                    };
+                }
+                _ => return None,
+            }
+            Some(rules)
+        }
+
+        fn season_2024_25() -> ScoringRules {
+            let points = [
+                (
+                    MatchStatistic::GoalsScored,
+                    PositionPoints {
+                        goalkeeper: 10,
+                        defender: 6,
+                        midfielder: 5,
+                        forward: 4,
+                    },
+                ),
+                (MatchStatistic::Assists, PositionPoints::all(3)),
+                (
+                    MatchStatistic::CleanSheets,
+                    PositionPoints {
+                        goalkeeper: 4,
+                        defender: 4,
+                        midfielder: 1,
+                        forward: 0,
+                    },
+                ),
+                (
+                    MatchStatistic::GoalsConceded,
+                    PositionPoints {
+                        goalkeeper: -1,
+                        defender: -1,
+                        midfielder: 0,
+                        forward: 0,
+                    },
+                ),
+                (MatchStatistic::Bps, PositionPoints::all(0)),
+                (MatchStatistic::Bonus, PositionPoints::all(1)),
+                (MatchStatistic::OwnGoals, PositionPoints::all(-2)),
+                (MatchStatistic::YellowCards, PositionPoints::all(-1)),
+                (MatchStatistic::RedCards, PositionPoints::all(-3)),
+                (MatchStatistic::Saves, PositionPoints::goalkeeper(1)),
+                (MatchStatistic::PenaltiesMissed, PositionPoints::all(-2)),
+                (MatchStatistic::PenaltiesSaved, PositionPoints::goalkeeper(5)),
+                (MatchStatistic::Minutes, PositionPoints::all(1)),
+            ];
+
+            ScoringRules {
+                points: points.into_iter().collect(),
+                saves_per_point: 3,
+                goals_conceded_per_point: 2,
+                expected_goals_per_point: 100,
+                long_play_minutes: 60,
+                long_play_points: 2,
+                short_play_points: 1,
+                clean_sheet_minutes: 60,
+                defensive_contribution_threshold: PositionPoints::all(0),
+                bonus_by_rank: vec![3, 2, 1],
+            }
+        }
+    }
+
+    // Scoring rules written by hand, for leagues with house rules. Points take the same form as
+    // the game settings: one value for every position, or one each for GKP, DEF, MID and FWD.
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct ScoringRulesFile {
+        season: Option<String>, // The built-in rules to start from, otherwise the latest
+        #[serde(default)]
+        points: BTreeMap<String, FplApiScoringValue>,
+        saves_per_point: Option<i32>,
+        goals_conceded_per_point: Option<i32>,
+        expected_goals_per_point: Option<f64>,
+        long_play_minutes: Option<i32>,
+        clean_sheet_minutes: Option<i32>,
+        defensive_contribution_threshold: Option<FplApiScoringValue>,
+        bonus_by_rank: Option<Vec<i32>>,
+    }
+
+    impl ScoringRulesFile {
+        fn into_rules(self) -> Result<ScoringRules, String> {
+            let base_rules = match &self.season {
+                Some(season) => ScoringRules::for_season(season)
+                    .ok_or_else(|| format!("No built-in scoring rules for {}", season))?,
+                None => ScoringRules::default(),
+            };
+
+            // Unlike the game settings, a statistic we do not know is most likely a typo
+            let unknown = self.points.keys().find(|identifier| {
+                !matches!(identifier.as_str(), "long_play" | "short_play")
+                    && MatchStatistic::from(identifier).is_err()
+            });
+            if let Some(identifier) = unknown {
+                return Err(format!("Unknown statistic {} in the scoring rules", identifier));
+            }
+
+            let mut rules =
+                fpl_conversions::apply_scoring_values(&self.points, base_rules, "the scoring rules")?;
+            if let Some(saves_per_point) = self.saves_per_point {
+                rules.saves_per_point = saves_per_point;
+            }
+            if let Some(goals_conceded_per_point) = self.goals_conceded_per_point {
+                rules.goals_conceded_per_point = goals_conceded_per_point;
+            }
+            if let Some(expected_goals_per_point) = self.expected_goals_per_point {
+                rules.expected_goals_per_point = (expected_goals_per_point * 100.0).round() as i32;
+            }
+            if let Some(long_play_minutes) = self.long_play_minutes {
+                rules.long_play_minutes = long_play_minutes;
+            }
+            if let Some(clean_sheet_minutes) = self.clean_sheet_minutes {
+                rules.clean_sheet_minutes = clean_sheet_minutes;
+            }
+            if let Some(threshold) = &self.defensive_contribution_threshold {
+                rules.defensive_contribution_threshold = fpl_conversions::convert_position_points(
+                    "defensive_contribution_threshold",
+                    threshold,
+                    "the scoring rules",
+                )?;
+            }
+            if let Some(bonus_by_rank) = self.bonus_by_rank {
+                rules.bonus_by_rank = bonus_by_rank;
+            }
+            Ok(rules)
+        }
     }
 
-    pub fn bonus_points_rank_to_points(rank: usize) -> i32 {
-        match rank {
-            1 => 3,
-            2 => 2,
-            3 => 1,
-            _ => 0,
+    impl ScoringRules {
+        pub fn from_toml(text: &str) -> Result<ScoringRules, String> {
+            let file: ScoringRulesFile =
+                toml::from_str(text).map_err(|e| format!("Invalid scoring rules: {}", e))?;
+            file.into_rules()
+        }
+
+        pub fn from_json(text: &str) -> Result<ScoringRules, String> {
+            let file: ScoringRulesFile =
+                serde_json::from_str(text).map_err(|e| format!("Invalid scoring rules: {}", e))?;
+            file.into_rules()
+        }
+
+        // Read a rules file, picking the format from its extension
+        pub fn load<P: AsRef<Path>>(path: P) -> Result<ScoringRules, String> {
+            let path = path.as_ref();
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+            match path.extension().and_then(|extension| extension.to_str()) {
+                Some("toml") => ScoringRules::from_toml(&text),
+                Some("json") => ScoringRules::from_json(&text),
+                _ => Err(format!(
+                    "Expected a .toml or .json scoring rules file, not {}",
+                    path.display()
+                )),
+            }
         }
     }
 
-    // TODO: unit testing for these arrays
+    pub fn bonus_points_rank_to_points(rules: &ScoringRules, rank: usize) -> i32 {
+        rank.checked_sub(1)
+            .and_then(|index| rules.bonus_by_rank.get(index))
+            .cloned()
+            .unwrap_or(0)
+    }
 
     pub fn points_multiplier(
+        rules: &ScoringRules,
+        match_stat: &MatchStatistic,
+        player_position: &fpl_positions::Position,
+    ) -> i32 {
+        rules
+            .points
+            .get(match_stat)
+            .map(|points| points.for_position(player_position))
+            .unwrap_or(0)
+    }
+
+    // The points a statistic earns a player, given the rest of their statistics in the match
+    pub fn statistic_points(
+        rules: &ScoringRules,
         match_stat: &MatchStatistic,
+        stat_value: i32,
+        player_stats: &MatchStatisticValueMap,
         player_position: &fpl_positions::Position,
     ) -> i32 {
+        // Without any minutes recorded, trust the clean sheet as the API only awards one after
+        // the player has been on the pitch long enough
+        if *match_stat == MatchStatistic::CleanSheets
+            && player_stats
+                .get(&MatchStatistic::Minutes)
+                .is_some_and(|&minutes| minutes < rules.clean_sheet_minutes)
+        {
+            return 0;
+        }
+        // Defensive contributions only count once there are enough for the player's position
+        let threshold = rules.defensive_contribution_threshold.for_position(player_position);
+        if *match_stat == MatchStatistic::DefensiveContribution && stat_value < threshold {
+            return 0;
+        }
+
+        points_multiplier(rules, match_stat, player_position)
+            * points_calculator(rules, match_stat, stat_value)
+    }
+
+    // Describe the rule statistic_points applies, e.g. "4 points a goal for a forward"
+    pub fn describe_rule(
+        rules: &ScoringRules,
+        match_stat: &MatchStatistic,
+        player_stats: &MatchStatisticValueMap,
+        player_position: &fpl_positions::Position,
+    ) -> String {
+        let worth = points_text(points_multiplier(rules, match_stat, player_position));
+        let position = match player_position {
+            fpl_positions::Position::GK => "goalkeeper",
+            fpl_positions::Position::DEF => "defender",
+            fpl_positions::Position::MID => "midfielder",
+            fpl_positions::Position::FWD => "forward",
+        };
+
         match match_stat {
-            MatchStatistic::GoalsScored => match player_position {
-                fpl_positions::Position::FWD => 4,
-                fpl_positions::Position::MID => 5,
-                fpl_positions::Position::DEF | fpl_positions::Position::GK => 6,
+            MatchStatistic::Minutes => match player_stats.get(&MatchStatistic::Minutes) {
+                Some(&minutes) if minutes >= rules.long_play_minutes => format!(
+                    "{} for {} minutes or more",
+                    points_text(rules.long_play_points),
+                    rules.long_play_minutes
+                ),
+                Some(&minutes) if minutes > 0 => format!(
+                    "{} for under {} minutes",
+                    points_text(rules.short_play_points),
+                    rules.long_play_minutes
+                ),
+                _ => String::from("No points without playing"),
             },
-            MatchStatistic::Assists => 3,
-            MatchStatistic::Bps => 0,
-            MatchStatistic::Bonus => 1,
-            MatchStatistic::OwnGoals => -2,
-            MatchStatistic::YellowCards => -1,
-            MatchStatistic::RedCards => -3,
-            MatchStatistic::Saves => 3,
-            MatchStatistic::PenaltiesMissed => -2,
-            MatchStatistic::PenaltiesSaved => 5,
-            MatchStatistic::Minutes => 1,
+            MatchStatistic::CleanSheets => match player_stats.get(&MatchStatistic::Minutes) {
+                Some(&minutes) if minutes < rules.clean_sheet_minutes => format!(
+                    "No clean sheet for under {} minutes",
+                    rules.clean_sheet_minutes
+                ),
+                _ => format!("{} a clean sheet for a {}", worth, position),
+            },
+            MatchStatistic::GoalsScored => format!("{} a goal for a {}", worth, position),
+            MatchStatistic::Assists => format!("{} an assist", worth),
+            MatchStatistic::GoalsConceded => format!(
+                "{} every {} goals conceded for a {}",
+                worth, rules.goals_conceded_per_point, position
+            ),
+            MatchStatistic::OwnGoals => format!("{} an own goal", worth),
+            MatchStatistic::PenaltiesSaved => format!("{} a penalty saved for a {}", worth, position),
+            MatchStatistic::PenaltiesMissed => format!("{} a penalty missed", worth),
+            MatchStatistic::YellowCards => format!("{} a yellow card", worth),
+            MatchStatistic::RedCards => format!("{} a red card", worth),
+            MatchStatistic::Saves => format!(
+                "{} every {} saves for a {}",
+                worth, rules.saves_per_point, position
+            ),
+            MatchStatistic::Bonus => format!("{} a bonus point", worth),
+            MatchStatistic::Bps => String::from("Only decides the bonus points"),
+            MatchStatistic::DefensiveContribution => format!(
+                "{} for {} or more defensive contributions for a {}",
+                worth,
+                rules.defensive_contribution_threshold.for_position(player_position),
+                position
+            ),
+            MatchStatistic::ExpectedGoals => format!(
+                "{} every {:.2} expected goals",
+                worth,
+                f64::from(rules.expected_goals_per_point) / 100.0
+            ),
+        }
+    }
+
+    fn points_text(points: i32) -> String {
+        if points.abs() == 1 {
+            format!("{} point", points)
+        } else {
+            format!("{} points", points)
         }
     }
 
-    pub fn points_calculator(match_stat: &MatchStatistic, stat_value: i32) -> i32 {
+    pub fn points_calculator(rules: &ScoringRules, match_stat: &MatchStatistic, stat_value: i32) -> i32 {
         match match_stat {
             MatchStatistic::GoalsScored => stat_value,
             MatchStatistic::Assists => stat_value,
+            MatchStatistic::CleanSheets => stat_value,
+            MatchStatistic::GoalsConceded => stat_value / rules.goals_conceded_per_point.max(1),
             MatchStatistic::Bps => 0, // You can't get points for Bps
             MatchStatistic::Bonus => stat_value,
             MatchStatistic::OwnGoals => stat_value,
             MatchStatistic::YellowCards => stat_value,
             MatchStatistic::RedCards => stat_value,
-            MatchStatistic::Saves => stat_value / 3,
+            MatchStatistic::Saves => stat_value / rules.saves_per_point.max(1),
             MatchStatistic::PenaltiesMissed => stat_value,
             MatchStatistic::PenaltiesSaved => stat_value,
+            MatchStatistic::DefensiveContribution => stat_value.min(1), // Scores once a match at most
+            MatchStatistic::ExpectedGoals => stat_value / rules.expected_goals_per_point.max(1),
             MatchStatistic::Minutes => match stat_value {
-                minutes if minutes > 60 => 2,
-                minutes if minutes > 0 => 1,
+                minutes if minutes >= rules.long_play_minutes => rules.long_play_points,
+                minutes if minutes > 0 => rules.short_play_points,
                 _ => 0,
             },
         }
     }
 }
 
-mod fpl_fixtures {
+pub mod fpl_fixtures {
     use chrono::{DateTime, Utc};
 
     use crate::{
         fpl_match_stats::{
-            points_calculator, points_multiplier, MatchStatistic, MatchStatisticMap,
-            MatchStatisticValueMap,
+            describe_rule, statistic_points, MatchStatisticMap, MatchStatisticValueMap,
+            PointsBreakdown, ScoringRules, StatisticPoints,
         },
+        fpl_live::LiveGameweek,
         fpl_players::{self, FplPlayer},
         fpl_teams,
     };
 
-    #[derive(Debug)]
+    #[derive(Debug, PartialEq)]
     pub struct MatchScore {
         pub home: u32,
         pub away: u32,
@@ -505,11 +991,11 @@ mod fpl_fixtures {
     #[derive(Debug)]
     pub struct Match {
         pub code: u32,
-        pub event: u32,
+        pub event: Option<u32>, // Postponed fixtures have no gameweek or kickoff until rescheduled
         pub finished: bool,
         pub finished_provisional: bool,
         pub id: u32,
-        pub kickoff_time: DateTime<Utc>,
+        pub kickoff_time: Option<DateTime<Utc>>,
         pub minutes: u32,
         pub provisional_start_time: bool,
         pub started: bool,
@@ -536,22 +1022,64 @@ mod fpl_fixtures {
             }
         }
 
-        fn points_for_match<'a>(
+        // The fixture list has no minutes, clean sheets or goals conceded, so fill them in from
+        // the live gameweek before scoring
+        pub fn add_live_statistics(&mut self, live: &LiveGameweek) {
+            if let Some(live_stats) = live.fixture_stats.get(&self.id) {
+                self.stats.add_missing_statistics(live_stats);
+            }
+        }
+
+        pub fn points_for_match<'a>(
             &mut self,
             player_list: &'a fpl_players::FplPlayerList,
-        ) -> Vec<(&'a FplPlayer, i32)> {
-            self.stats.get_player_points_from_stats(player_list)
+            rules: &ScoringRules,
+        ) -> Result<Vec<(&'a FplPlayer, i32)>, String> {
+            self.stats.get_player_points_from_stats(player_list, rules)
+        }
+
+        pub fn points_breakdown(
+            &mut self,
+            player_list: &fpl_players::FplPlayerList,
+            rules: &ScoringRules,
+        ) -> Result<Vec<PointsBreakdown>, String> {
+            self.stats.get_player_points_breakdown(self.id, player_list, rules)
         }
     }
 
-    pub fn player_points(stats_list: &MatchStatisticValueMap, player: &fpl_players::FplPlayer) -> i32 {
-        let get_stat_points = |(stat, stat_value): (&MatchStatistic, &i32)| -> i32 {
-            points_multiplier(&stat, &player.position) * points_calculator(&stat, *stat_value)
-        };
+    // The breakdown is the one place points are worked out; this is just its total
+    pub fn player_points(
+        rules: &ScoringRules,
+        stats_list: &MatchStatisticValueMap,
+        player: &fpl_players::FplPlayer,
+    ) -> i32 {
+        breakdown_total(&player_points_breakdown(rules, stats_list, player))
+    }
+
+    pub(crate) fn breakdown_total(breakdown: &[StatisticPoints]) -> i32 {
+        breakdown.iter().map(|stat| stat.points).sum()
+    }
 
-        stats_list.iter().map(get_stat_points).sum()
+    // Every statistic the player has, with what it scored and why, in a fixed order
+    pub fn player_points_breakdown(
+        rules: &ScoringRules,
+        stats_list: &MatchStatisticValueMap,
+        player: &fpl_players::FplPlayer,
+    ) -> Vec<StatisticPoints> {
+        let mut breakdown: Vec<StatisticPoints> = stats_list
+            .iter()
+            .map(|(stat, stat_value)| StatisticPoints {
+                identifier: *stat,
+                value: *stat_value,
+                points: statistic_points(rules, stat, *stat_value, stats_list, &player.position),
+                rule: describe_rule(rules, stat, stats_list, &player.position),
+            })
+            .collect();
+        breakdown.sort_by_key(|stat| stat.identifier);
+        breakdown
     }
 
+    #[derive(Default)]
     pub struct MatchList {
        pub match_list: Vec<Match>
     }
@@ -570,137 +1098,1420 @@ mod fpl_fixtures {
     }
 }
 
-mod fpl_conversions {
-    use chrono::DateTime;
+pub mod fpl_player_summary {
+    use chrono::{DateTime, Utc};
 
-    use crate::fpl_fixtures;
-    use crate::fpl_fixtures::MatchScore;
-    use crate::fpl_match_stats::MatchStatistic;
-    use crate::fpl_match_stats::MatchStatisticMap;
-    use crate::fpl_players;
-    use crate::fpl_positions;
-    use crate::fpl_teams;
+    use crate::{
+        fpl_fixtures::MatchScore,
+        fpl_players::{FplPlayer, FplPlayerList, FplPlayerStats},
+    };
 
-    pub fn convert_position(
-        api_position: &fpl_data::fpl_data::FplApiPosition,
-    ) -> Result<fpl_positions::FplPosition, &'static str> {
-        let fpl_position = fpl_positions::FplPosition {
-            id: api_position.id,
-            squad_select: api_position.squad_select,
-            squad_min_play: api_position.squad_min_play,
-            squad_max_play: api_position.squad_max_play,
-            element_count: api_position.element_count,
-            position: fpl_positions::Position::try_from(api_position.id - 1)
-                .expect("Unexpected position ID, could not map to a position GK/DEF/MID/FWD"),
-        };
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerGameweekRecord {
+        pub fixture_id: u32,
+        pub gameweek: u32,
+        pub opponent_team_id: u64,
+        pub was_home: bool,
+        pub kickoff_time: DateTime<Utc>,
+        pub score: Option<MatchScore>,
+        pub total_points: i32,
+        pub bonus: u32,
+        pub bps: i32,
+        pub stats: FplPlayerStats,
+        pub value: u32, // Price in tenths of a million, like now_cost
+        pub selected: u64,
+        pub transfers_balance: i64,
+    }
 
-        Ok(fpl_position)
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerUpcomingFixture {
+        pub fixture_id: u32,
+        pub gameweek: Option<u32>, // Unscheduled fixtures have no gameweek yet
+        pub kickoff_time: Option<DateTime<Utc>>,
+        pub opponent_team_id: u64,
+        pub is_home: bool,
+        pub difficulty: u32,
     }
 
-    pub fn convert_player(api_player: &fpl_data::fpl_data::FplApiPlayer) -> fpl_players::FplPlayer {
-        fpl_players::FplPlayer {
-            id: api_player.id,
-            name: fpl_players::FplPlayerName {
-                first_name: api_player.first_name.clone(),
-                second_name: api_player.second_name.clone(),
-                display_name: api_player.web_name.clone(),
-            },
-            position: fpl_positions::Position::try_from(api_player.element_type - 1)
-                .expect("Could not convert position"),
-            stats: fpl_players::FplPlayerStats {
-                minutes: api_player.minutes,
-                goals_scored: api_player.goals_scored,
-                assists: api_player.assists,
-                clean_sheets: api_player.clean_sheets,
-                goals_conceded: api_player.goals_conceded,
-                own_goals: api_player.own_goals,
-                yellow_cards: api_player.yellow_cards,
-                red_cards: api_player.red_cards,
-                saves: api_player.saves,
-                starts: api_player.starts,
-            },
-            stats_per_90: fpl_players::FplPlayerStatsPer90 {
-                starts: api_player.starts_per_90,
-                goals:
-                    // We have to manually generate this because for some reason the API won't provide it
-                    // And the xG is a different value
-                    if api_player.minutes == 0 { 0.0 }
-                    else { (api_player.goals_scored as f64) / ((api_player.minutes as f64) / 90.0)},
-                goals_conceded: api_player.goals_conceded_per_90,
-                clean_sheets: api_player.clean_sheets_per_90,
-            },
-            expected_stats: fpl_players::FplPlayerExpectations {
-                expected_goals: api_player
-                    .expected_goals
-                    .parse()
-                    .expect("Expected goals was not a number"),
-                expected_assists: api_player
-                    .expected_assists
-                    .parse()
-                    .expect("Expected assists was not a number"),
-                expected_goal_involvements: api_player
-                    .expected_goal_involvements
-                    .parse()
-                    .expect("Expected goal involvements was not a number"),
-                expected_goals_conceded: api_player
-                    .expected_goals_conceded
-                    .parse()
-                    .expect("Expected goals conceded was not a number"),
-                expected_clean_sheets: api_player
-                    .expected_goals_conceded
-                    .parse()
-                    .expect("Expected goals conceded was not a number"),
-            },
-            points_record: fpl_players::FplPlayerPointsRecord {
-                total_points: api_player.total_points,
-                bps: api_player.bps,
-                event_points: api_player.event_points,
-            },
-        }
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerSeasonRecord {
+        pub season_name: String,
+        pub player_code: u32,
+        pub start_cost: u32,
+        pub end_cost: u32,
+        pub total_points: i32,
+        pub bonus: u32,
+        pub bps: i32,
+        pub stats: FplPlayerStats,
     }
 
-    pub fn convert_player_list(
-        api_player_list: &Vec<fpl_data::fpl_data::FplApiPlayer>,
-    ) -> Result<fpl_players::FplPlayerList, &str> {
-        let mut player_list = fpl_players::FplPlayerList::new();
+    #[derive(Debug, PartialEq)]
+    pub struct FplPlayerSummary {
+        pub player_id: u32,
+        pub history: Vec<FplPlayerGameweekRecord>,
+        pub fixtures: Vec<FplPlayerUpcomingFixture>,
+        pub past_seasons: Vec<FplPlayerSeasonRecord>,
+    }
 
-        api_player_list.into_iter().for_each(|api_player| {
-            player_list.add_player(api_player.id, convert_player(api_player))
-        });
+    impl FplPlayerSummary {
+        pub fn player<'a>(&self, player_list: &'a FplPlayerList) -> Option<&'a FplPlayer> {
+            player_list.get_player(&self.player_id)
+        }
 
-        Ok(player_list)
+        pub fn next_fixture(&self) -> Option<&FplPlayerUpcomingFixture> {
+            self.fixtures.first()
+        }
+
+        pub fn points_in_gameweek(&self, gameweek: u32) -> i32 {
+            self.history
+                .iter()
+                .filter(|record| record.gameweek == gameweek)
+                .map(|record| record.total_points)
+                .sum()
+        }
+
+        pub fn past_season(&self, season_name: &str) -> Option<&FplPlayerSeasonRecord> {
+            self.past_seasons
+                .iter()
+                .find(|season| season.season_name == season_name)
+        }
     }
+}
 
-    pub fn convert_team(
-        api_team: &fpl_data::fpl_data::FplApiTeam,
-    ) -> Result<fpl_teams::FplTeam, &str> {
-        Ok(fpl_teams::FplTeam {
-            id: api_team.id,
-            name: api_team.name.clone(),
-            short_name: api_team.short_name.clone(),
-            table_data: fpl_teams::FplTeamTableData {
-                played: api_team.played,
-                win: api_team.win,
-                draw: api_team.draw,
-                loss: api_team.loss,
-                points: api_team.points,
-                position: api_team.position,
-            },
-            strength: fpl_teams::FplTeamStrength {
-                overall_home: api_team.strength_overall_home,
-                overall_away: api_team.strength_overall_away,
-                attack_home: api_team.strength_attack_home,
-                attack_away: api_team.strength_attack_away,
-                defence_home: api_team.strength_defence_home,
-                defence_away: api_team.strength_defence_away,
-            },
-        })
+pub mod fpl_live {
+    use std::collections::HashMap;
+
+    use crate::fpl_match_stats::MatchStatisticMap;
+
+    // A gameweek in progress, split into the same per-fixture statistics the fixtures
+    // endpoint gives, so the points engine can run on it.
+    #[derive(Debug)]
+    pub struct LiveGameweek {
+        pub gameweek: u32,
+        pub fixture_stats: HashMap<u32, MatchStatisticMap>,
+        pub official_points: HashMap<u32, i32>, // Player id -> FPL's own total for the gameweek
     }
 
-    pub fn convert_fixture(
-        api_fixture: &fpl_data::fpl_data::FplApiFixture,
-    ) -> Result<fpl_fixtures::Match, String> {
-        let match_score = if let (Some(home_score), Some(away_score)) =
+    impl LiveGameweek {
+        pub fn fixture_ids(&self) -> Vec<u32> {
+            let mut fixture_ids: Vec<u32> = self.fixture_stats.keys().cloned().collect();
+            fixture_ids.sort();
+            fixture_ids
+        }
+
+        pub fn get_fixture_stats_mut(&mut self, fixture_id: u32) -> Option<&mut MatchStatisticMap> {
+            self.fixture_stats.get_mut(&fixture_id)
+        }
+
+        pub fn official_points(&self, player_id: u32) -> Option<i32> {
+            self.official_points.get(&player_id).cloned()
+        }
+    }
+}
+
+pub mod fpl_managers {
+    use chrono::{DateTime, Utc};
+
+    use crate::{
+        fpl_game_settings::SquadRules,
+        fpl_players::{FplPlayer, FplPlayerList},
+    };
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplManager {
+        pub id: u32,
+        pub team_name: String,
+        pub player_name: String,
+        pub region: String,
+        pub favourite_team_id: Option<u64>,
+        pub started_gameweek: u32,
+        pub overall_points: Option<i32>,
+        pub overall_rank: Option<u32>,
+        pub bank: Option<i32>,  // Tenths of a million
+        pub value: Option<i32>, // Tenths of a million
+        pub classic_league_ids: Vec<u32>,
+        pub h2h_league_ids: Vec<u32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Chip {
+        Wildcard,
+        FreeHit,
+        BenchBoost,
+        TripleCaptain,
+        AssistantManager,
+        Other(String),
+    }
+
+    impl Chip {
+        pub fn from(api_name: &str) -> Chip {
+            match api_name {
+                "wildcard" => Chip::Wildcard,
+                "freehit" => Chip::FreeHit,
+                "bboost" => Chip::BenchBoost,
+                "3xc" => Chip::TripleCaptain,
+                "manager" => Chip::AssistantManager,
+                other => Chip::Other(other.to_string()),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplChipPlay {
+        pub chip: Chip,
+        pub gameweek: u32,
+        pub played_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplManagerGameweek {
+        pub gameweek: u32,
+        pub points: i32,
+        pub total_points: i32,
+        pub rank: Option<u32>,
+        pub overall_rank: Option<u32>,
+        pub bank: i32,
+        pub value: i32,
+        pub transfers: u32,
+        pub transfers_cost: i32,
+        pub points_on_bench: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplManagerSeason {
+        pub season_name: String,
+        pub total_points: i32,
+        pub rank: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplManagerHistory {
+        pub manager_id: u32,
+        pub gameweeks: Vec<FplManagerGameweek>,
+        pub past_seasons: Vec<FplManagerSeason>,
+        pub chips: Vec<FplChipPlay>,
+    }
+
+    impl FplManagerHistory {
+        pub fn gameweek(&self, gameweek: u32) -> Option<&FplManagerGameweek> {
+            self.gameweeks.iter().find(|record| record.gameweek == gameweek)
+        }
+
+        pub fn chip_played_in(&self, gameweek: u32) -> Option<&Chip> {
+            self.chips
+                .iter()
+                .find(|play| play.gameweek == gameweek)
+                .map(|play| &play.chip)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplSquadPick {
+        pub player_id: u32,
+        pub position: u32, // Squad slot, the starting players first and then the bench in order
+        pub multiplier: u32,
+        pub is_captain: bool,
+        pub is_vice_captain: bool,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplManagerSquad {
+        pub manager_id: u32,
+        pub gameweek: u32,
+        pub active_chip: Option<Chip>,
+        pub picks: Vec<FplSquadPick>,
+        pub automatic_subs: Vec<(u32, u32)>, // (player in, player out)
+        pub points: i32,
+        pub points_on_bench: i32,
+    }
+
+    impl FplManagerSquad {
+        pub fn captain(&self) -> Option<&FplSquadPick> {
+            self.picks.iter().find(|pick| pick.is_captain)
+        }
+
+        pub fn vice_captain(&self) -> Option<&FplSquadPick> {
+            self.picks.iter().find(|pick| pick.is_vice_captain)
+        }
+
+        pub fn starting_xi(&self, rules: &SquadRules) -> Vec<&FplSquadPick> {
+            self.picks
+                .iter()
+                .filter(|pick| pick.position <= rules.starting_players)
+                .collect()
+        }
+
+        pub fn bench(&self, rules: &SquadRules) -> Vec<&FplSquadPick> {
+            self.picks
+                .iter()
+                .filter(|pick| pick.position > rules.starting_players)
+                .collect()
+        }
+
+        // Picks resolved against the player list, None where the player is not known
+        pub fn players<'a>(&self, player_list: &'a FplPlayerList) -> Vec<Option<&'a FplPlayer>> {
+            self.picks
+                .iter()
+                .map(|pick| player_list.get_player(&pick.player_id))
+                .collect()
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FplTransfer {
+        pub gameweek: u32,
+        pub player_in: u32,
+        pub player_in_cost: u32,
+        pub player_out: u32,
+        pub player_out_cost: u32,
+        pub time: DateTime<Utc>,
+    }
+}
+
+pub mod fpl_gameweeks {
+    use std::collections::HashMap;
+
+    use chrono::{DateTime, Utc};
+
+    use crate::{
+        fpl_managers::Chip,
+        fpl_players::{FplPlayer, FplPlayerList},
+    };
+
+    #[derive(Debug, PartialEq)]
+    pub struct Gameweek {
+        pub id: u32,
+        pub name: String,
+        pub deadline: DateTime<Utc>,
+        pub release_time: Option<DateTime<Utc>>,
+        pub finished: bool,
+        pub data_checked: bool,
+        // The API keeps a finished gameweek current until the next deadline, so a
+        // gameweek can be both previous and current
+        pub is_previous: bool,
+        pub is_current: bool,
+        pub is_next: bool,
+        pub average_entry_score: u32,
+        pub highest_score: Option<u32>,
+        pub highest_scoring_entry: Option<u32>,
+        pub chip_plays: HashMap<Chip, u32>,
+        pub most_selected: Option<u32>, // Player ids
+        pub most_transferred_in: Option<u32>,
+        pub most_captained: Option<u32>,
+        pub most_vice_captained: Option<u32>,
+        pub top_element: Option<u32>,
+        pub top_element_points: Option<u32>,
+        pub transfers_made: u64,
+    }
+
+    impl Gameweek {
+        pub fn chip_play_count(&self, chip: &Chip) -> u32 {
+            self.chip_plays.get(chip).cloned().unwrap_or(0)
+        }
+
+        pub fn top_player<'a>(&self, player_list: &'a FplPlayerList) -> Option<&'a FplPlayer> {
+            player_list.get_player(&self.top_element?)
+        }
+
+        pub fn most_captained_player<'a>(
+            &self,
+            player_list: &'a FplPlayerList,
+        ) -> Option<&'a FplPlayer> {
+            player_list.get_player(&self.most_captained?)
+        }
+
+        pub fn most_selected_player<'a>(
+            &self,
+            player_list: &'a FplPlayerList,
+        ) -> Option<&'a FplPlayer> {
+            player_list.get_player(&self.most_selected?)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct GameweekList {
+        pub gameweeks: Vec<Gameweek>, // In gameweek order
+    }
+
+    impl GameweekList {
+        pub fn get(&self, gameweek_id: u32) -> Option<&Gameweek> {
+            self.gameweeks
+                .iter()
+                .find(|gameweek| gameweek.id == gameweek_id)
+        }
+
+        pub fn current(&self) -> Option<&Gameweek> {
+            self.gameweeks.iter().find(|gameweek| gameweek.is_current)
+        }
+
+        pub fn next(&self) -> Option<&Gameweek> {
+            self.gameweeks.iter().find(|gameweek| gameweek.is_next)
+        }
+
+        pub fn previous(&self) -> Option<&Gameweek> {
+            self.gameweeks.iter().find(|gameweek| gameweek.is_previous)
+        }
+
+        pub fn next_deadline(&self) -> Option<DateTime<Utc>> {
+            self.next().map(|gameweek| gameweek.deadline)
+        }
+
+        // The first gameweek whose deadline is still to come at the given time, for when
+        // the flags from the last download may be out of date
+        pub fn next_deadline_after(&self, time: DateTime<Utc>) -> Option<&Gameweek> {
+            self.gameweeks
+                .iter()
+                .filter(|gameweek| gameweek.deadline > time)
+                .min_by_key(|gameweek| gameweek.deadline)
+        }
+    }
+}
+
+pub mod fpl_leagues {
+    use std::future::Future;
+
+    use fpl_data::{fpl_client::FplClient, fpl_data::FplDataError};
+    use tokio::task::JoinSet;
+
+    use crate::{
+        fpl_conversions,
+        fpl_managers::{FplManager, FplManagerSquad},
+    };
+
+    // How many member requests are in flight at once when the client sets no limit of its own
+    pub const DEFAULT_MEMBER_CONCURRENCY: usize = 8;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LeagueKind {
+        Classic,
+        HeadToHead,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct H2hRecord {
+        pub played: u32,
+        pub won: u32,
+        pub drawn: u32,
+        pub lost: u32,
+        pub points_for: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct LeagueMember {
+        pub manager_id: u32,
+        pub team_name: String,
+        pub player_name: String,
+        pub rank: u32,
+        pub last_rank: u32,
+        pub total: i32, // FPL points in classic leagues, league points in H2H
+        pub gameweek_points: Option<i32>, // Classic leagues only
+        pub h2h_record: Option<H2hRecord>, // H2H leagues only
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct H2hMatch {
+        pub gameweek: u32,
+        pub manager_1: Option<u32>, // None for the empty side of a bye
+        pub manager_1_points: i32,
+        pub manager_2: Option<u32>,
+        pub manager_2_points: i32,
+        pub winner: Option<u32>, // None for a draw
+        pub is_knockout: bool,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct League {
+        pub id: u32,
+        pub name: String,
+        pub kind: LeagueKind,
+        pub members: Vec<LeagueMember>, // In rank order
+        pub matches: Vec<H2hMatch>,
+    }
+
+    impl League {
+        pub fn manager_ids(&self) -> Vec<u32> {
+            self.members.iter().map(|member| member.manager_id).collect()
+        }
+
+        pub fn member(&self, manager_id: u32) -> Option<&LeagueMember> {
+            self.members
+                .iter()
+                .find(|member| member.manager_id == manager_id)
+        }
+
+        pub fn leader(&self) -> Option<&LeagueMember> {
+            self.members.iter().min_by_key(|member| member.rank)
+        }
+
+        pub fn matches_in_gameweek(&self, gameweek: u32) -> Vec<&H2hMatch> {
+            self.matches
+                .iter()
+                .filter(|h2h_match| h2h_match.gameweek == gameweek)
+                .collect()
+        }
+
+        // Fetch every member's entry, returned in the same order as the members
+        pub async fn fetch_member_entries(
+            &self,
+            client: &FplClient,
+        ) -> Result<Vec<FplManager>, FplDataError> {
+            self.fetch_for_members(client, |client, manager_id| async move {
+                let api_entry = client.get_entry(manager_id).await?;
+                Ok(fpl_conversions::convert_manager(&api_entry))
+            })
+            .await
+        }
+
+        pub async fn fetch_member_squads(
+            &self,
+            client: &FplClient,
+            gameweek: u32,
+        ) -> Result<Vec<FplManagerSquad>, FplDataError> {
+            self.fetch_for_members(client, move |client, manager_id| async move {
+                let api_picks = client.get_entry_picks(manager_id, gameweek).await?;
+                Ok(fpl_conversions::convert_manager_squad(
+                    manager_id, gameweek, &api_picks,
+                ))
+            })
+            .await
+        }
+
+        // Runs one request per member, never more at once than the client's concurrency limit.
+        // The first failure is returned and the requests still running are cancelled.
+        async fn fetch_for_members<T, F, Fut>(
+            &self,
+            client: &FplClient,
+            fetch: F,
+        ) -> Result<Vec<T>, FplDataError>
+        where
+            T: Send + 'static,
+            F: Fn(FplClient, u32) -> Fut,
+            Fut: Future<Output = Result<T, FplDataError>> + Send + 'static,
+        {
+            let limit = client
+                .max_concurrency()
+                .unwrap_or(DEFAULT_MEMBER_CONCURRENCY);
+            let mut pending = self.manager_ids().into_iter().enumerate();
+            let mut results: Vec<Option<T>> = self.members.iter().map(|_| None).collect();
+            let mut tasks = JoinSet::new();
+
+            loop {
+                while tasks.len() < limit {
+                    let Some((index, manager_id)) = pending.next() else {
+                        break;
+                    };
+                    let request = fetch(client.clone(), manager_id);
+                    tasks.spawn(async move { (index, request.await) });
+                }
+
+                let Some(joined) = tasks.join_next().await else {
+                    break;
+                };
+                let (index, result) = joined.map_err(|error| FplDataError::Transport {
+                    url: client.base_url().to_string(),
+                    message: format!("League member request did not complete: {}", error),
+                })?;
+                results[index] = Some(result?);
+            }
+
+            Ok(results.into_iter().flatten().collect())
+        }
+    }
+}
+
+pub mod fpl_game_settings {
+    use std::collections::HashMap;
+
+    use crate::{
+        fpl_managers::{Chip, FplManagerSquad},
+        fpl_match_stats::ScoringRules,
+        fpl_players::FplPlayerList,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SquadRules {
+        pub squad_size: u32,
+        pub starting_players: u32,
+        pub max_per_team: u32,
+        pub budget: u32, // Tenths of a million
+        pub transfers_cap: u32,
+        pub max_extra_free_transfers: u32,
+        pub sell_on_fee: f64, // The share of a player's price rise kept when selling
+    }
+
+    impl Default for SquadRules {
+        fn default() -> Self {
+            SquadRules {
+                squad_size: 15,
+                starting_players: 11,
+                max_per_team: 3,
+                budget: 1000,
+                transfers_cap: 20,
+                max_extra_free_transfers: 4,
+                sell_on_fee: 0.5,
+            }
+        }
+    }
+
+    impl SquadRules {
+        // Everything wrong with a squad under these rules, empty if it is legal
+        pub fn squad_problems(
+            &self,
+            squad: &FplManagerSquad,
+            player_list: &FplPlayerList,
+        ) -> Vec<String> {
+            let mut problems = Vec::new();
+            if squad.picks.len() as u32 != self.squad_size {
+                problems.push(format!(
+                    "The squad has {} players rather than {}",
+                    squad.picks.len(),
+                    self.squad_size
+                ));
+            }
+
+            let mut per_team: HashMap<u64, u32> = HashMap::new();
+            for player in squad.players(player_list).into_iter().flatten() {
+                *per_team.entry(player.team_id).or_default() += 1;
+            }
+            let mut crowded: Vec<_> = per_team
+                .into_iter()
+                .filter(|&(_, count)| count > self.max_per_team)
+                .collect();
+            crowded.sort();
+            for (team_id, count) in crowded {
+                problems.push(format!(
+                    "The squad has {} players from team {}, the limit is {}",
+                    count, team_id, self.max_per_team
+                ));
+            }
+            problems
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct H2hPoints {
+        pub win: u32,
+        pub draw: u32,
+        pub loss: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Phase {
+        pub id: u32,
+        pub name: String,
+        pub start_gameweek: u32,
+        pub stop_gameweek: u32,
+        pub highest_score: Option<u32>,
+    }
+
+    impl Phase {
+        pub fn contains(&self, gameweek: u32) -> bool {
+            (self.start_gameweek..=self.stop_gameweek).contains(&gameweek)
+        }
+    }
+
+    // When a chip may be played, and how many times in that window
+    #[derive(Debug, PartialEq)]
+    pub struct ChipWindow {
+        pub chip: Chip,
+        pub number: u32,
+        pub start_gameweek: u32,
+        pub stop_gameweek: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct GameSettings {
+        pub squad: SquadRules,
+        pub scoring: ScoringRules,
+        pub h2h_points: H2hPoints,
+        pub phases: Vec<Phase>,
+        pub chips: Vec<ChipWindow>,
+    }
+
+    impl GameSettings {
+        pub fn phases_for(&self, gameweek: u32) -> Vec<&Phase> {
+            self.phases
+                .iter()
+                .filter(|phase| phase.contains(gameweek))
+                .collect()
+        }
+
+        pub fn chips_available_in(&self, gameweek: u32) -> Vec<&ChipWindow> {
+            self.chips
+                .iter()
+                .filter(|window| (window.start_gameweek..=window.stop_gameweek).contains(&gameweek))
+                .collect()
+        }
+    }
+}
+
+pub mod fpl_changes {
+    use chrono::{DateTime, Utc};
+
+    use crate::fpl_players::{FplPlayer, FplPlayerList};
+
+    pub use ::fpl_data::fpl_diff::SetPiece;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum PlayerStatus {
+        Available,
+        Doubtful,
+        Injured,
+        Suspended,
+        NotAvailable, // On loan, or not eligible to play
+        Unavailable,  // Left the club
+        Other(String),
+    }
+
+    impl PlayerStatus {
+        pub fn from(api_status: &str) -> PlayerStatus {
+            match api_status {
+                "a" => PlayerStatus::Available,
+                "d" => PlayerStatus::Doubtful,
+                "i" => PlayerStatus::Injured,
+                "s" => PlayerStatus::Suspended,
+                "n" => PlayerStatus::NotAvailable,
+                "u" => PlayerStatus::Unavailable,
+                other => PlayerStatus::Other(other.to_string()),
+            }
+        }
+    }
+
+    // The prices and set piece orders carry over from fpl_diff::PlayerChange
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PlayerEvent {
+        NewPlayer,
+        PlayerRemoved,
+        PriceRise { from: u32, to: u32 },
+        PriceFall { from: u32, to: u32 },
+        StatusChanged { from: PlayerStatus, to: PlayerStatus },
+        ChanceOfPlayingThisRound { from: Option<u32>, to: Option<u32> },
+        ChanceOfPlayingNextRound { from: Option<u32>, to: Option<u32> },
+        NewsPosted { news: String, added: Option<DateTime<Utc>> },
+        NewsCleared,
+        Transferred { from_team_id: u32, to_team_id: u32 },
+        SetPieceOrderChanged { set_piece: SetPiece, from: Option<u32>, to: Option<u32> },
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PlayerChangeEvent {
+        pub player_id: u32,
+        pub event: PlayerEvent,
+    }
+
+    impl PlayerChangeEvent {
+        // None for a removed player, who is no longer in the list
+        pub fn player<'a>(&self, player_list: &'a FplPlayerList) -> Option<&'a FplPlayer> {
+            player_list.get_player(&self.player_id)
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct ChangeSet {
+        pub events: Vec<PlayerChangeEvent>, // By player id
+    }
+
+    impl ChangeSet {
+        pub fn is_empty(&self) -> bool {
+            self.events.is_empty()
+        }
+
+        pub fn for_player(&self, player_id: u32) -> Vec<&PlayerEvent> {
+            self.events
+                .iter()
+                .filter(|change| change.player_id == player_id)
+                .map(|change| &change.event)
+                .collect()
+        }
+
+        pub fn price_changes(&self) -> Vec<&PlayerChangeEvent> {
+            self.events
+                .iter()
+                .filter(|change| {
+                    matches!(
+                        change.event,
+                        PlayerEvent::PriceRise { .. } | PlayerEvent::PriceFall { .. }
+                    )
+                })
+                .collect()
+        }
+
+        // Players whose status moved away from available, e.g. an injury or suspension
+        pub fn newly_unavailable(&self) -> Vec<&PlayerChangeEvent> {
+            self.events
+                .iter()
+                .filter(|change| {
+                    matches!(
+                        &change.event,
+                        PlayerEvent::StatusChanged { from: PlayerStatus::Available, to }
+                            if *to != PlayerStatus::Available
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+pub mod fpl_seasons {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::{
+        fpl_fixtures::MatchList,
+        fpl_match_stats::ScoringRules,
+        fpl_player_summary::FplPlayerGameweekRecord,
+        fpl_players::FplPlayerList,
+        fpl_teams::FplTeam,
+    };
+
+    // A finished season imported from the archive. Ids are only unique within a season.
+    pub struct FplSeason {
+        pub season: String, // e.g. "2023-24"
+        pub teams: Vec<FplTeam>,
+        pub players: FplPlayerList,
+        pub matches: MatchList,
+        pub player_gameweeks: HashMap<u32, Vec<FplPlayerGameweekRecord>>, // By player id
+    }
+
+    impl FplSeason {
+        pub fn team(&self, team_id: u64) -> Option<&FplTeam> {
+            self.teams.iter().find(|team| team.id == team_id)
+        }
+
+        pub fn player_gameweeks(&self, player_id: u32) -> &[FplPlayerGameweekRecord] {
+            self.player_gameweeks
+                .get(&player_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+        }
+
+        pub fn points_in_gameweek(&self, player_id: u32, gameweek: u32) -> i32 {
+            self.player_gameweeks(player_id)
+                .iter()
+                .filter(|record| record.gameweek == gameweek)
+                .map(|record| record.total_points)
+                .sum()
+        }
+
+        // The official scoring the season was played under, if we know it
+        pub fn scoring_rules(&self) -> Option<ScoringRules> {
+            ScoringRules::for_season(&self.season)
+        }
+    }
+
+    pub struct FplSeasonHistory {
+        pub seasons: BTreeMap<String, FplSeason>, // Keyed by season, oldest first
+    }
+
+    impl FplSeasonHistory {
+        pub fn season(&self, season: &str) -> Option<&FplSeason> {
+            self.seasons.get(season)
+        }
+    }
+}
+
+pub mod fpl_registry {
+    use std::collections::BTreeMap;
+
+    use crate::{fpl_players::FplPlayerList, fpl_seasons::FplSeasonHistory, fpl_teams::FplTeam};
+
+    // Where a player appeared in one season
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PlayerSeason {
+        pub season: String,
+        pub player_id: u32,
+        pub team_code: Option<u64>, // None if the season's teams did not include the club
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TeamSeason {
+        pub season: String,
+        pub team_id: u64,
+    }
+
+    // A player's move between clubs from one season to the next
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ClubChange {
+        pub player_code: u32,
+        pub from_season: String,
+        pub from_team_code: u64,
+        pub to_season: String,
+        pub to_team_code: u64,
+    }
+
+    // Links players and clubs across seasons by their codes, which unlike ids do not change.
+    // Seasons are named like "2023-24", so they sort in the order they were played.
+    #[derive(Debug, Default)]
+    pub struct FplRegistry {
+        players: BTreeMap<u32, Vec<PlayerSeason>>, // By player code, oldest season first
+        teams: BTreeMap<u64, Vec<TeamSeason>>,     // By team code, oldest season first
+        seasons: Vec<String>,
+    }
+
+    impl FplRegistry {
+        pub fn new() -> Self {
+            FplRegistry::default()
+        }
+
+        pub fn from_history(history: &FplSeasonHistory) -> Self {
+            let mut registry = FplRegistry::new();
+            for season in history.seasons.values() {
+                registry.add_season(&season.season, &season.teams, &season.players);
+            }
+            registry
+        }
+
+        // Adding a season that is already known replaces it
+        pub fn add_season(&mut self, season: &str, teams: &[FplTeam], players: &FplPlayerList) {
+            self.remove_season(season);
+
+            for team in teams {
+                let seasons = self.teams.entry(team.code).or_default();
+                seasons.push(TeamSeason {
+                    season: season.to_string(),
+                    team_id: team.id,
+                });
+                seasons.sort_by(|a, b| a.season.cmp(&b.season));
+            }
+
+            for player in players.player_list.values() {
+                let team_code = teams
+                    .iter()
+                    .find(|team| team.id == player.team_id)
+                    .map(|team| team.code);
+                let seasons = self.players.entry(player.code).or_default();
+                seasons.push(PlayerSeason {
+                    season: season.to_string(),
+                    player_id: player.id,
+                    team_code,
+                });
+                seasons.sort_by(|a, b| a.season.cmp(&b.season));
+            }
+
+            self.seasons.push(season.to_string());
+            self.seasons.sort();
+        }
+
+        fn remove_season(&mut self, season: &str) {
+            self.seasons.retain(|known| known != season);
+            for seasons in self.players.values_mut() {
+                seasons.retain(|entry| entry.season != season);
+            }
+            for seasons in self.teams.values_mut() {
+                seasons.retain(|entry| entry.season != season);
+            }
+        }
+
+        pub fn seasons(&self) -> &[String] {
+            &self.seasons
+        }
+
+        pub fn player_seasons(&self, player_code: u32) -> &[PlayerSeason] {
+            self.players
+                .get(&player_code)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+        }
+
+        pub fn team_seasons(&self, team_code: u64) -> &[TeamSeason] {
+            self.teams
+                .get(&team_code)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+        }
+
+        pub fn player_code(&self, season: &str, player_id: u32) -> Option<u32> {
+            self.players.iter().find_map(|(code, seasons)| {
+                seasons
+                    .iter()
+                    .any(|entry| entry.season == season && entry.player_id == player_id)
+                    .then_some(*code)
+            })
+        }
+
+        pub fn team_code(&self, season: &str, team_id: u64) -> Option<u64> {
+            self.teams.iter().find_map(|(code, seasons)| {
+                seasons
+                    .iter()
+                    .any(|entry| entry.season == season && entry.team_id == team_id)
+                    .then_some(*code)
+            })
+        }
+
+        pub fn player_id_in(&self, season: &str, player_code: u32) -> Option<u32> {
+            self.player_seasons(player_code)
+                .iter()
+                .find(|entry| entry.season == season)
+                .map(|entry| entry.player_id)
+        }
+
+        pub fn team_id_in(&self, season: &str, team_code: u64) -> Option<u64> {
+            self.team_seasons(team_code)
+                .iter()
+                .find(|entry| entry.season == season)
+                .map(|entry| entry.team_id)
+        }
+
+        // The id a player from one season has in another, if they played in both
+        pub fn same_player(&self, season: &str, player_id: u32, other_season: &str) -> Option<u32> {
+            self.player_id_in(other_season, self.player_code(season, player_id)?)
+        }
+
+        pub fn same_team(&self, season: &str, team_id: u64, other_season: &str) -> Option<u64> {
+            self.team_id_in(other_season, self.team_code(season, team_id)?)
+        }
+
+        fn previous_season(&self, season: &str) -> Option<&str> {
+            let index = self.seasons.iter().position(|known| known == season)?;
+            index.checked_sub(1).map(|index| self.seasons[index].as_str())
+        }
+
+        fn team_codes_in(&self, season: &str) -> Vec<u64> {
+            self.teams
+                .iter()
+                .filter(|(_, seasons)| seasons.iter().any(|entry| entry.season == season))
+                .map(|(code, _)| *code)
+                .collect()
+        }
+
+        // Clubs that are in a season but were not in the one before, i.e. promoted. Empty
+        // for the first season known.
+        pub fn promoted_teams(&self, season: &str) -> Vec<u64> {
+            let previous = match self.previous_season(season) {
+                Some(previous) => self.team_codes_in(previous),
+                None => return Vec::new(),
+            };
+            self.team_codes_in(season)
+                .into_iter()
+                .filter(|code| !previous.contains(code))
+                .collect()
+        }
+
+        // Clubs that were in the season before but are not in this one, i.e. relegated
+        pub fn relegated_teams(&self, season: &str) -> Vec<u64> {
+            let previous = match self.previous_season(season) {
+                Some(previous) => self.team_codes_in(previous),
+                None => return Vec::new(),
+            };
+            let current = self.team_codes_in(season);
+            previous
+                .into_iter()
+                .filter(|code| !current.contains(code))
+                .collect()
+        }
+
+        // Every change of club between consecutive appearances of a player
+        pub fn club_changes(&self, player_code: u32) -> Vec<ClubChange> {
+            self.player_seasons(player_code)
+                .windows(2)
+                .filter_map(|pair| match (pair[0].team_code, pair[1].team_code) {
+                    (Some(from_team_code), Some(to_team_code)) if from_team_code != to_team_code => {
+                        Some(ClubChange {
+                            player_code,
+                            from_season: pair[0].season.clone(),
+                            from_team_code,
+                            to_season: pair[1].season.clone(),
+                            to_team_code,
+                        })
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        // The players who arrived at a new club for the given season
+        pub fn transfers_into(&self, season: &str) -> Vec<ClubChange> {
+            self.players
+                .keys()
+                .flat_map(|&code| self.club_changes(code))
+                .filter(|change| change.to_season == season)
+                .collect()
+        }
+    }
+}
+
+pub mod fpl_dataset {
+    use std::fmt;
+
+    use fpl_data::{
+        fpl_client::FplClient,
+        fpl_data::{BootstrapStatic, FplApiFixture, FplDataError},
+    };
+
+    use crate::{
+        fpl_conversions,
+        fpl_fixtures::{Match, MatchList},
+        fpl_game_settings::GameSettings,
+        fpl_gameweeks::{Gameweek, GameweekList},
+        fpl_live::LiveGameweek,
+        fpl_match_stats::PointsBreakdown,
+        fpl_players::{FplPlayer, FplPlayerList},
+        fpl_positions::{FplPosition, Position},
+        fpl_teams::FplTeam,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum FplDatasetError {
+        // Fetching or parsing the API data failed
+        Data(FplDataError),
+        // The API data was read, but could not be converted into the domain types
+        Conversion(String),
+    }
+
+    impl fmt::Display for FplDatasetError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FplDatasetError::Data(error) => write!(f, "{}", error),
+                FplDatasetError::Conversion(message) => {
+                    write!(f, "Failed to convert the API data: {}", message)
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for FplDatasetError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                FplDatasetError::Data(error) => Some(error),
+                FplDatasetError::Conversion(_) => None,
+            }
+        }
+    }
+
+    impl From<FplDataError> for FplDatasetError {
+        fn from(error: FplDataError) -> Self {
+            FplDatasetError::Data(error)
+        }
+    }
+
+    // Everything bootstrap-static and the fixture list describe, as domain types
+    pub struct FplDataset {
+        pub teams: Vec<FplTeam>,
+        pub players: FplPlayerList,
+        pub positions: Vec<FplPosition>,
+        pub fixtures: MatchList,
+        pub gameweeks: GameweekList,
+        pub settings: GameSettings,
+    }
+
+    impl FplDataset {
+        // Fetch bootstrap-static and the fixtures side by side, then convert both
+        pub async fn load(client: &FplClient) -> Result<Self, FplDatasetError> {
+            let (bootstrap, fixtures) =
+                tokio::try_join!(client.get_bootstrap_static(), client.get_fixtures())?;
+            Self::from_api(&bootstrap, &fixtures)
+        }
+
+        pub fn from_api(
+            bootstrap: &BootstrapStatic,
+            fixtures: &[FplApiFixture],
+        ) -> Result<Self, FplDatasetError> {
+            let conversion_error = |message: &str| FplDatasetError::Conversion(message.to_string());
+
+            let teams = bootstrap
+                .teams
+                .iter()
+                .map(|team| fpl_conversions::convert_team(team).map_err(conversion_error))
+                .collect::<Result<Vec<_>, _>>()?;
+            let players = fpl_conversions::convert_player_list(&bootstrap.elements)
+                .map_err(FplDatasetError::Conversion)?;
+            let positions = bootstrap
+                .element_types
+                .iter()
+                .map(fpl_conversions::convert_position)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(FplDatasetError::Conversion)?;
+            let match_list = fixtures
+                .iter()
+                .map(fpl_conversions::convert_fixture)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(FplDatasetError::Conversion)?;
+            let gameweeks = fpl_conversions::convert_gameweek_list(&bootstrap.events)
+                .map_err(FplDatasetError::Conversion)?;
+            let settings = fpl_conversions::convert_game_settings(bootstrap)
+                .map_err(FplDatasetError::Conversion)?;
+
+            Ok(FplDataset {
+                teams,
+                players,
+                positions,
+                fixtures: MatchList { match_list },
+                gameweeks,
+                settings,
+            })
+        }
+
+        pub fn team(&self, team_id: u64) -> Option<&FplTeam> {
+            self.teams.iter().find(|team| team.id == team_id)
+        }
+
+        pub fn team_by_short_name(&self, short_name: &str) -> Option<&FplTeam> {
+            self.teams.iter().find(|team| team.short_name == short_name)
+        }
+
+        pub fn player(&self, player_id: u32) -> Option<&FplPlayer> {
+            self.players.get_player(&player_id)
+        }
+
+        // Players come out ordered by id, as the player list itself has no order
+        pub fn players_in_team(&self, team_id: u64) -> Vec<&FplPlayer> {
+            self.players_where(|player| player.team_id == team_id)
+        }
+
+        pub fn players_in_position(&self, position: &Position) -> Vec<&FplPlayer> {
+            self.players_where(|player| player.position == *position)
+        }
+
+        fn players_where(&self, filter: impl Fn(&FplPlayer) -> bool) -> Vec<&FplPlayer> {
+            let mut players: Vec<&FplPlayer> = self
+                .players
+                .player_list
+                .values()
+                .filter(|player| filter(player))
+                .collect();
+            players.sort_by_key(|player| player.id);
+            players
+        }
+
+        pub fn position(&self, position: &Position) -> Option<&FplPosition> {
+            self.positions
+                .iter()
+                .find(|fpl_position| fpl_position.position == *position)
+        }
+
+        pub fn fixture(&self, fixture_id: u32) -> Option<&Match> {
+            self.fixtures
+                .match_list
+                .iter()
+                .find(|fixture| fixture.id == fixture_id)
+        }
+
+        pub fn fixtures_in_gameweek(&self, gameweek: u32) -> Vec<&Match> {
+            self.fixtures
+                .match_list
+                .iter()
+                .filter(|fixture| fixture.event == Some(gameweek))
+                .collect()
+        }
+
+        pub fn team_fixtures(&self, team_id: u64) -> Vec<&Match> {
+            self.fixtures
+                .match_list
+                .iter()
+                .filter(|fixture| fixture.home_team_id == team_id || fixture.away_team_id == team_id)
+                .collect()
+        }
+
+        pub fn current_gameweek(&self) -> Option<&Gameweek> {
+            self.gameweeks.current()
+        }
+
+        // Score every player in a fixture with this season's scoring rules. The fixture list
+        // lacks minutes, clean sheets and goals conceded, so they come from the fixture's live
+        // gameweek. None if there is no such fixture, an error if the live data is for another
+        // gameweek or has stats for a player the dataset does not know.
+        pub fn points_in_fixture(
+            &mut self,
+            fixture_id: u32,
+            live: &LiveGameweek,
+        ) -> Option<Result<Vec<(&FplPlayer, i32)>, FplDatasetError>> {
+            let fixture = Self::fixture_with_live_statistics(&mut self.fixtures, fixture_id, live)?;
+            Some(fixture.and_then(|fixture| {
+                fixture
+                    .points_for_match(&self.players, &self.settings.scoring)
+                    .map_err(FplDatasetError::Conversion)
+            }))
+        }
+
+        pub fn points_breakdown_in_fixture(
+            &mut self,
+            fixture_id: u32,
+            live: &LiveGameweek,
+        ) -> Option<Result<Vec<PointsBreakdown>, FplDatasetError>> {
+            let fixture = Self::fixture_with_live_statistics(&mut self.fixtures, fixture_id, live)?;
+            Some(fixture.and_then(|fixture| {
+                fixture
+                    .points_breakdown(&self.players, &self.settings.scoring)
+                    .map_err(FplDatasetError::Conversion)
+            }))
+        }
+
+        fn fixture_with_live_statistics<'a>(
+            fixtures: &'a mut MatchList,
+            fixture_id: u32,
+            live: &LiveGameweek,
+        ) -> Option<Result<&'a mut Match, FplDatasetError>> {
+            let fixture = fixtures
+                .match_list
+                .iter_mut()
+                .find(|fixture| fixture.id == fixture_id)?;
+            if fixture.event != Some(live.gameweek) {
+                return Some(Err(FplDatasetError::Conversion(format!(
+                    "Fixture {} is not in gameweek {}, which the live data is for",
+                    fixture_id, live.gameweek
+                ))));
+            }
+            fixture.add_live_statistics(live);
+            Some(Ok(fixture))
+        }
+    }
+}
+
+pub mod fpl_conversions {
+    use std::collections::{BTreeMap, HashMap};
+
+    use chrono::{DateTime, Datelike, Utc};
+
+    use crate::fpl_changes;
+    use crate::fpl_fixtures;
+    use crate::fpl_fixtures::MatchScore;
+    use crate::fpl_game_settings;
+    use crate::fpl_gameweeks;
+    use crate::fpl_leagues;
+    use crate::fpl_live;
+    use crate::fpl_managers;
+    use crate::fpl_match_stats::MatchStatistic;
+    use crate::fpl_match_stats::MatchStatisticMap;
+    use crate::fpl_match_stats::{PositionPoints, ScoringRules};
+    use crate::fpl_player_summary;
+    use crate::fpl_players;
+    use crate::fpl_positions;
+    use crate::fpl_seasons;
+    use crate::fpl_teams;
+
+    // Element types count from 1 for goalkeepers. Anything past forwards (such as the
+    // assistant managers of 2024/25) has no position to map to.
+    fn convert_element_type(element_type: u32) -> Result<fpl_positions::Position, String> {
+        element_type
+            .checked_sub(1)
+            .and_then(|position| fpl_positions::Position::try_from(position).ok())
+            .ok_or_else(|| format!("Element type {} is not a GK/DEF/MID/FWD position", element_type))
+    }
+
+    pub fn convert_position(
+        api_position: &fpl_data::fpl_data::FplApiPosition,
+    ) -> Result<fpl_positions::FplPosition, String> {
+        let fpl_position = fpl_positions::FplPosition {
+            id: api_position.id,
+            squad_select: api_position.squad_select,
+            squad_min_play: api_position.squad_min_play,
+            squad_max_play: api_position.squad_max_play,
+            element_count: api_position.element_count,
+            position: convert_element_type(api_position.id)?,
+        };
+
+        Ok(fpl_position)
+    }
+
+    pub fn convert_player(
+        api_player: &fpl_data::fpl_data::FplApiPlayer,
+    ) -> Result<fpl_players::FplPlayer, String> {
+        let position = convert_element_type(api_player.element_type)
+            .map_err(|e| format!("Player {} ({}): {}", api_player.id, api_player.web_name, e))?;
+
+        Ok(fpl_players::FplPlayer {
+            id: api_player.id,
+            code: api_player.code,
+            team_id: u64::from(api_player.team),
+            name: fpl_players::FplPlayerName {
+                first_name: api_player.first_name.clone(),
+                second_name: api_player.second_name.clone(),
+                display_name: api_player.web_name.clone(),
+            },
+            position,
+            stats: fpl_players::FplPlayerStats {
+                minutes: api_player.minutes,
+                goals_scored: api_player.goals_scored,
+                assists: api_player.assists,
+                clean_sheets: api_player.clean_sheets,
+                goals_conceded: api_player.goals_conceded,
+                own_goals: api_player.own_goals,
+                yellow_cards: api_player.yellow_cards,
+                red_cards: api_player.red_cards,
+                saves: api_player.saves,
+                starts: api_player.starts,
+            },
+            stats_per_90: fpl_players::FplPlayerStatsPer90 {
+                starts: api_player.starts_per_90,
+                goals:
+                    // We have to manually generate this because for some reason the API won't provide it
+                    // And the xG is a different value
+                    if api_player.minutes == 0 { 0.0 }
+                    else { (api_player.goals_scored as f64) / ((api_player.minutes as f64) / 90.0)},
+                goals_conceded: api_player.goals_conceded_per_90,
+                clean_sheets: api_player.clean_sheets_per_90,
+            },
+            expected_stats: fpl_players::FplPlayerExpectations {
+                expected_goals: api_player.expected_goals,
+                expected_assists: api_player.expected_assists,
+                expected_goal_involvements: api_player.expected_goal_involvements,
+                expected_goals_conceded: api_player.expected_goals_conceded,
+            },
+            form: fpl_players::FplPlayerForm {
+                form: api_player.form,
+                points_per_game: api_player.points_per_game,
+                selected_by_percent: api_player.selected_by_percent,
+                value_form: api_player.value_form,
+                value_season: api_player.value_season,
+                expected_points_this: api_player.ep_this,
+                expected_points_next: api_player.ep_next,
+            },
+            ict: fpl_players::FplPlayerIct {
+                influence: api_player.influence,
+                creativity: api_player.creativity,
+                threat: api_player.threat,
+                ict_index: api_player.ict_index,
+            },
+            points_record: fpl_players::FplPlayerPointsRecord {
+                total_points: api_player.total_points,
+                bps: api_player.bps,
+                event_points: api_player.event_points,
+            },
+        })
+    }
+
+    pub fn convert_player_list(
+        api_player_list: &Vec<fpl_data::fpl_data::FplApiPlayer>,
+    ) -> Result<fpl_players::FplPlayerList, String> {
+        let mut player_list = fpl_players::FplPlayerList::new();
+
+        for api_player in api_player_list {
+            player_list.add_player(api_player.id, convert_player(api_player)?);
+        }
+
+        Ok(player_list)
+    }
+
+    pub fn convert_team(
+        api_team: &fpl_data::fpl_data::FplApiTeam,
+    ) -> Result<fpl_teams::FplTeam, &str> {
+        Ok(fpl_teams::FplTeam {
+            id: api_team.id,
+            code: api_team.code,
+            name: api_team.name.clone(),
+            short_name: api_team.short_name.clone(),
+            table_data: fpl_teams::FplTeamTableData {
+                played: api_team.played,
+                win: api_team.win,
+                draw: api_team.draw,
+                loss: api_team.loss,
+                points: api_team.points,
+                position: api_team.position,
+            },
+            strength: fpl_teams::FplTeamStrength {
+                overall_home: api_team.strength_overall_home,
+                overall_away: api_team.strength_overall_away,
+                attack_home: api_team.strength_attack_home,
+                attack_away: api_team.strength_attack_away,
+                defence_home: api_team.strength_defence_home,
+                defence_away: api_team.strength_defence_away,
+            },
+        })
+    }
+
+    pub fn convert_fixture(
+        api_fixture: &fpl_data::fpl_data::FplApiFixture,
+    ) -> Result<fpl_fixtures::Match, String> {
+        let match_score = if let (Some(home_score), Some(away_score)) =
             (api_fixture.team_h_score, api_fixture.team_a_score)
         {
             Some(MatchScore {
@@ -711,72 +2522,735 @@ mod fpl_conversions {
             None
         };
 
-        // The array "match_stats" is a map of player -> statistics
-        let mut match_stats = MatchStatisticMap::new();
+        // The array "match_stats" is a map of player -> statistics
+        let mut match_stats = MatchStatisticMap::new();
+
+        // Statistics the points engine does not model yet are skipped
+        for stat in &api_fixture.stats {
+            if let Ok(statistic) = MatchStatistic::from(&stat.identifier) {
+                for player_id in stat.h.iter().chain(stat.a.iter()) {
+                    match_stats.add_statistic(player_id.element, statistic, player_id.value);
+                }
+            }
+        }
+
+        Ok(fpl_fixtures::Match {
+            code: api_fixture.code,
+            event: api_fixture.event,
+            finished: api_fixture.finished,
+            finished_provisional: api_fixture.finished_provisional,
+            id: api_fixture.id,
+            kickoff_time: api_fixture
+                .kickoff_time
+                .as_deref()
+                .map(parse_api_time)
+                .transpose()?,
+            minutes: api_fixture.minutes,
+            provisional_start_time: api_fixture.provisional_start_time,
+            started: api_fixture.started,
+            home_team_id: api_fixture.team_h,
+            away_team_id: api_fixture.team_a,
+            score: match_score,
+            stats: match_stats,
+        })
+    }
+
+    pub fn convert_fixture_list(
+        api_fixture_list: &Vec<fpl_data::fpl_data::FplApiFixture>,
+    ) -> Result<fpl_fixtures::MatchList, String> {
+        let mut fixture_list = fpl_fixtures::MatchList::new();
+
+        for api_fixture in api_fixture_list {
+            fixture_list.add_fixture(convert_fixture(api_fixture)?);
+        }
+
+        Ok(fixture_list)
+    }
+
+    fn parse_api_time(time: &str) -> Result<DateTime<Utc>, String> {
+        DateTime::parse_from_rfc3339(time)
+            .map(|time| time.with_timezone(&Utc))
+            .map_err(|e| format!("Invalid time {}: {}", time, e))
+    }
+
+    // One fixture of a player's season, from their summary or the season archive
+    pub fn convert_player_gameweek(
+        record: &fpl_data::fpl_data::FplApiPlayerHistory,
+    ) -> Result<fpl_player_summary::FplPlayerGameweekRecord, String> {
+        Ok(fpl_player_summary::FplPlayerGameweekRecord {
+            fixture_id: record.fixture,
+            gameweek: record.round,
+            opponent_team_id: record.opponent_team,
+            was_home: record.was_home,
+            kickoff_time: parse_api_time(&record.kickoff_time)?,
+            score: match (record.team_h_score, record.team_a_score) {
+                (Some(home), Some(away)) => Some(MatchScore { home, away }),
+                _ => None,
+            },
+            total_points: record.total_points,
+            bonus: record.bonus,
+            bps: record.bps,
+            stats: fpl_players::FplPlayerStats {
+                minutes: record.minutes,
+                goals_scored: record.goals_scored,
+                assists: record.assists,
+                clean_sheets: record.clean_sheets,
+                goals_conceded: record.goals_conceded,
+                own_goals: record.own_goals,
+                yellow_cards: record.yellow_cards,
+                red_cards: record.red_cards,
+                saves: record.saves,
+                starts: record.starts,
+            },
+            value: record.value,
+            selected: record.selected,
+            transfers_balance: record.transfers_balance,
+        })
+    }
+
+    pub fn convert_player_summary(
+        player_id: u32,
+        api_summary: &fpl_data::fpl_data::FplApiElementSummary,
+    ) -> Result<fpl_player_summary::FplPlayerSummary, String> {
+        let history = api_summary
+            .history
+            .iter()
+            .map(convert_player_gameweek)
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let fixtures = api_summary
+            .fixtures
+            .iter()
+            .map(|fixture| {
+                Ok(fpl_player_summary::FplPlayerUpcomingFixture {
+                    fixture_id: fixture.id,
+                    gameweek: fixture.event,
+                    kickoff_time: fixture
+                        .kickoff_time
+                        .as_deref()
+                        .map(parse_api_time)
+                        .transpose()?,
+                    opponent_team_id: if fixture.is_home {
+                        fixture.team_a
+                    } else {
+                        fixture.team_h
+                    },
+                    is_home: fixture.is_home,
+                    difficulty: fixture.difficulty,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let past_seasons = api_summary
+            .history_past
+            .iter()
+            .map(|season| fpl_player_summary::FplPlayerSeasonRecord {
+                season_name: season.season_name.clone(),
+                player_code: season.element_code,
+                start_cost: season.start_cost,
+                end_cost: season.end_cost,
+                total_points: season.total_points,
+                bonus: season.bonus,
+                bps: season.bps,
+                stats: fpl_players::FplPlayerStats {
+                    minutes: season.minutes,
+                    goals_scored: season.goals_scored,
+                    assists: season.assists,
+                    clean_sheets: season.clean_sheets,
+                    goals_conceded: season.goals_conceded,
+                    own_goals: season.own_goals,
+                    yellow_cards: season.yellow_cards,
+                    red_cards: season.red_cards,
+                    saves: season.saves,
+                    starts: season.starts,
+                },
+            })
+            .collect();
+
+        Ok(fpl_player_summary::FplPlayerSummary {
+            player_id,
+            history,
+            fixtures,
+            past_seasons,
+        })
+    }
+
+    // Players with a single fixture get their full live stats (including bps, which the
+    // explain breakdown leaves out). In a double gameweek only the explain values can be
+    // attributed to each fixture.
+    pub fn convert_live_gameweek(
+        gameweek: u32,
+        api_live: &fpl_data::fpl_data::FplApiLiveGameweek,
+    ) -> Result<fpl_live::LiveGameweek, String> {
+        let mut fixture_stats: HashMap<u32, MatchStatisticMap> = HashMap::new();
+        let mut official_points = HashMap::new();
+
+        for element in &api_live.elements {
+            official_points.insert(element.id, element.stats.total_points);
+
+            if let [explain] = element.explain.as_slice() {
+                let stats = &element.stats;
+                let match_stats = fixture_stats
+                    .entry(explain.fixture)
+                    .or_default();
+                let live_values = [
+                    (MatchStatistic::Minutes, stats.minutes as i32),
+                    (MatchStatistic::GoalsScored, stats.goals_scored as i32),
+                    (MatchStatistic::Assists, stats.assists as i32),
+                    (MatchStatistic::CleanSheets, stats.clean_sheets as i32),
+                    (MatchStatistic::GoalsConceded, stats.goals_conceded as i32),
+                    (MatchStatistic::OwnGoals, stats.own_goals as i32),
+                    (MatchStatistic::PenaltiesSaved, stats.penalties_saved as i32),
+                    (MatchStatistic::PenaltiesMissed, stats.penalties_missed as i32),
+                    (MatchStatistic::YellowCards, stats.yellow_cards as i32),
+                    (MatchStatistic::RedCards, stats.red_cards as i32),
+                    (MatchStatistic::Saves, stats.saves as i32),
+                    (MatchStatistic::Bonus, stats.bonus as i32),
+                    (MatchStatistic::Bps, stats.bps),
+                    (MatchStatistic::DefensiveContribution, stats.defensive_contribution as i32),
+                    (MatchStatistic::ExpectedGoals, (stats.expected_goals * 100.0).round() as i32),
+                ];
+                for (statistic, value) in live_values {
+                    match_stats.add_statistic(element.id, statistic, value);
+                }
+            } else {
+                for explain in &element.explain {
+                    let match_stats = fixture_stats
+                        .entry(explain.fixture)
+                        .or_default();
+                    // Statistics the points engine does not model yet are skipped
+                    for stat in &explain.stats {
+                        if let Ok(statistic) = MatchStatistic::from(&stat.identifier) {
+                            match_stats.add_statistic(element.id, statistic, stat.value);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(fpl_live::LiveGameweek {
+            gameweek,
+            fixture_stats,
+            official_points,
+        })
+    }
+
+    pub fn convert_manager(api_entry: &fpl_data::fpl_data::FplApiEntry) -> fpl_managers::FplManager {
+        fpl_managers::FplManager {
+            id: api_entry.id,
+            team_name: api_entry.name.clone(),
+            player_name: format!(
+                "{} {}",
+                api_entry.player_first_name, api_entry.player_last_name
+            ),
+            region: api_entry.player_region_name.clone(),
+            favourite_team_id: api_entry.favourite_team,
+            started_gameweek: api_entry.started_event,
+            overall_points: api_entry.summary_overall_points,
+            overall_rank: api_entry.summary_overall_rank,
+            bank: api_entry.last_deadline_bank,
+            value: api_entry.last_deadline_value,
+            classic_league_ids: api_entry.leagues.classic.iter().map(|league| league.id).collect(),
+            h2h_league_ids: api_entry.leagues.h2h.iter().map(|league| league.id).collect(),
+        }
+    }
+
+    fn convert_manager_gameweek(
+        api_event: &fpl_data::fpl_data::FplApiEntryEvent,
+    ) -> fpl_managers::FplManagerGameweek {
+        fpl_managers::FplManagerGameweek {
+            gameweek: api_event.event,
+            points: api_event.points,
+            total_points: api_event.total_points,
+            rank: api_event.rank,
+            overall_rank: api_event.overall_rank,
+            bank: api_event.bank,
+            value: api_event.value,
+            transfers: api_event.event_transfers,
+            transfers_cost: api_event.event_transfers_cost,
+            points_on_bench: api_event.points_on_bench,
+        }
+    }
+
+    pub fn convert_manager_history(
+        manager_id: u32,
+        api_history: &fpl_data::fpl_data::FplApiEntryHistory,
+    ) -> Result<fpl_managers::FplManagerHistory, String> {
+        let chips = api_history
+            .chips
+            .iter()
+            .map(|chip| {
+                Ok(fpl_managers::FplChipPlay {
+                    chip: fpl_managers::Chip::from(&chip.name),
+                    gameweek: chip.event,
+                    played_at: parse_api_time(&chip.time)?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(fpl_managers::FplManagerHistory {
+            manager_id,
+            gameweeks: api_history.current.iter().map(convert_manager_gameweek).collect(),
+            past_seasons: api_history
+                .past
+                .iter()
+                .map(|season| fpl_managers::FplManagerSeason {
+                    season_name: season.season_name.clone(),
+                    total_points: season.total_points,
+                    rank: season.rank,
+                })
+                .collect(),
+            chips,
+        })
+    }
+
+    pub fn convert_manager_squad(
+        manager_id: u32,
+        gameweek: u32,
+        api_picks: &fpl_data::fpl_data::FplApiEntryPicks,
+    ) -> fpl_managers::FplManagerSquad {
+        let mut picks: Vec<fpl_managers::FplSquadPick> = api_picks
+            .picks
+            .iter()
+            .map(|pick| fpl_managers::FplSquadPick {
+                player_id: pick.element,
+                position: pick.position,
+                multiplier: pick.multiplier,
+                is_captain: pick.is_captain,
+                is_vice_captain: pick.is_vice_captain,
+            })
+            .collect();
+        picks.sort_by_key(|pick| pick.position);
+
+        fpl_managers::FplManagerSquad {
+            manager_id,
+            gameweek,
+            active_chip: api_picks.active_chip.as_deref().map(fpl_managers::Chip::from),
+            picks,
+            automatic_subs: api_picks
+                .automatic_subs
+                .iter()
+                .map(|sub| (sub.element_in, sub.element_out))
+                .collect(),
+            points: api_picks.entry_history.points,
+            points_on_bench: api_picks.entry_history.points_on_bench,
+        }
+    }
+
+    pub fn convert_transfers(
+        api_transfers: &[fpl_data::fpl_data::FplApiTransfer],
+    ) -> Result<Vec<fpl_managers::FplTransfer>, String> {
+        api_transfers
+            .iter()
+            .map(|transfer| {
+                Ok(fpl_managers::FplTransfer {
+                    gameweek: transfer.event,
+                    player_in: transfer.element_in,
+                    player_in_cost: transfer.element_in_cost,
+                    player_out: transfer.element_out,
+                    player_out_cost: transfer.element_out_cost,
+                    time: parse_api_time(&transfer.time)?,
+                })
+            })
+            .collect()
+    }
+
+    pub fn convert_classic_league(
+        api_league: &fpl_data::fpl_data::FplApiLeagueStandings<
+            fpl_data::fpl_data::FplApiClassicStanding,
+        >,
+    ) -> fpl_leagues::League {
+        fpl_leagues::League {
+            id: api_league.league.id,
+            name: api_league.league.name.clone(),
+            kind: fpl_leagues::LeagueKind::Classic,
+            members: api_league
+                .standings
+                .iter()
+                .map(|row| fpl_leagues::LeagueMember {
+                    manager_id: row.entry,
+                    team_name: row.entry_name.clone(),
+                    player_name: row.player_name.clone(),
+                    rank: row.rank,
+                    last_rank: row.last_rank,
+                    total: row.total,
+                    gameweek_points: Some(row.event_total),
+                    h2h_record: None,
+                })
+                .collect(),
+            matches: Vec::new(),
+        }
+    }
+
+    pub fn convert_h2h_league(
+        api_league: &fpl_data::fpl_data::FplApiLeagueStandings<
+            fpl_data::fpl_data::FplApiH2hStanding,
+        >,
+        api_matches: &[fpl_data::fpl_data::FplApiH2hMatch],
+    ) -> fpl_leagues::League {
+        fpl_leagues::League {
+            id: api_league.league.id,
+            name: api_league.league.name.clone(),
+            kind: fpl_leagues::LeagueKind::HeadToHead,
+            members: api_league
+                .standings
+                .iter()
+                .map(|row| fpl_leagues::LeagueMember {
+                    manager_id: row.entry,
+                    team_name: row.entry_name.clone(),
+                    player_name: row.player_name.clone(),
+                    rank: row.rank,
+                    last_rank: row.last_rank,
+                    total: row.total,
+                    gameweek_points: None,
+                    h2h_record: Some(fpl_leagues::H2hRecord {
+                        played: row.matches_played,
+                        won: row.matches_won,
+                        drawn: row.matches_drawn,
+                        lost: row.matches_lost,
+                        points_for: row.points_for,
+                    }),
+                })
+                .collect(),
+            matches: api_matches
+                .iter()
+                .map(|api_match| fpl_leagues::H2hMatch {
+                    gameweek: api_match.event,
+                    manager_1: api_match.entry_1_entry,
+                    manager_1_points: api_match.entry_1_points,
+                    manager_2: api_match.entry_2_entry,
+                    manager_2_points: api_match.entry_2_points,
+                    winner: api_match.winner,
+                    is_knockout: api_match.is_knockout,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn convert_gameweek(
+        api_gameweek: &fpl_data::fpl_data::FplApiGameweek,
+    ) -> Result<fpl_gameweeks::Gameweek, String> {
+        Ok(fpl_gameweeks::Gameweek {
+            id: api_gameweek.id,
+            name: api_gameweek.name.clone(),
+            deadline: parse_api_time(&api_gameweek.deadline_time)?,
+            release_time: api_gameweek
+                .release_time
+                .as_deref()
+                .map(parse_api_time)
+                .transpose()?,
+            finished: api_gameweek.finished,
+            data_checked: api_gameweek.data_checked,
+            is_previous: api_gameweek.is_previous,
+            is_current: api_gameweek.is_current,
+            is_next: api_gameweek.is_next,
+            average_entry_score: api_gameweek.average_entry_score,
+            highest_score: api_gameweek.highest_score,
+            highest_scoring_entry: api_gameweek.highest_scoring_entry,
+            chip_plays: api_gameweek
+                .chip_plays
+                .iter()
+                .map(|play| (fpl_managers::Chip::from(&play.chip_name), play.num_played))
+                .collect(),
+            most_selected: api_gameweek.most_selected,
+            most_transferred_in: api_gameweek.most_transferred_in,
+            most_captained: api_gameweek.most_captained,
+            most_vice_captained: api_gameweek.most_vice_captained,
+            top_element: api_gameweek.top_element,
+            top_element_points: api_gameweek
+                .top_element_info
+                .as_ref()
+                .and_then(|info| info.points),
+            transfers_made: api_gameweek.transfers_made,
+        })
+    }
+
+    pub fn convert_gameweek_list(
+        api_gameweeks: &[fpl_data::fpl_data::FplApiGameweek],
+    ) -> Result<fpl_gameweeks::GameweekList, String> {
+        let mut gameweeks = api_gameweeks
+            .iter()
+            .map(convert_gameweek)
+            .collect::<Result<Vec<_>, String>>()?;
+        gameweeks.sort_by_key(|gameweek| gameweek.id);
+
+        Ok(fpl_gameweeks::GameweekList { gameweeks })
+    }
+
+    pub fn convert_snapshot_diff(
+        api_diff: &fpl_data::fpl_diff::SnapshotDiff,
+    ) -> Result<fpl_changes::ChangeSet, String> {
+        use fpl_changes::PlayerEvent;
+        use fpl_data::fpl_diff::PlayerChange;
+
+        let events = api_diff
+            .players
+            .iter()
+            .map(|diff| {
+                let event = match &diff.change {
+                    PlayerChange::Added => PlayerEvent::NewPlayer,
+                    PlayerChange::Removed => PlayerEvent::PlayerRemoved,
+                    &PlayerChange::Cost { from, to } if to > from => {
+                        PlayerEvent::PriceRise { from, to }
+                    }
+                    &PlayerChange::Cost { from, to } => PlayerEvent::PriceFall { from, to },
+                    PlayerChange::Status { from, to } => PlayerEvent::StatusChanged {
+                        from: fpl_changes::PlayerStatus::from(from),
+                        to: fpl_changes::PlayerStatus::from(to),
+                    },
+                    &PlayerChange::ChanceOfPlayingThisRound { from, to } => {
+                        PlayerEvent::ChanceOfPlayingThisRound { from, to }
+                    }
+                    &PlayerChange::ChanceOfPlayingNextRound { from, to } => {
+                        PlayerEvent::ChanceOfPlayingNextRound { from, to }
+                    }
+                    PlayerChange::News { to, .. } if to.is_empty() => PlayerEvent::NewsCleared,
+                    PlayerChange::News { to, added, .. } => PlayerEvent::NewsPosted {
+                        news: to.clone(),
+                        added: added.as_deref().map(parse_api_time).transpose()?,
+                    },
+                    &PlayerChange::Team { from, to } => PlayerEvent::Transferred {
+                        from_team_id: from,
+                        to_team_id: to,
+                    },
+                    &PlayerChange::SetPieceOrder {
+                        set_piece,
+                        from,
+                        to,
+                    } => PlayerEvent::SetPieceOrderChanged {
+                        set_piece,
+                        from,
+                        to,
+                    },
+                };
+                Ok(fpl_changes::PlayerChangeEvent {
+                    player_id: diff.player_id,
+                    event,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(fpl_changes::ChangeSet { events })
+    }
+
+    // The changes between two downloads of bootstrap-static
+    pub fn convert_bootstrap_changes(
+        before: &fpl_data::fpl_data::BootstrapStatic,
+        after: &fpl_data::fpl_data::BootstrapStatic,
+    ) -> Result<fpl_changes::ChangeSet, String> {
+        convert_snapshot_diff(&fpl_data::fpl_diff::diff_bootstrap(before, after))
+    }
+
+    pub fn convert_archived_season(
+        archived: &fpl_data::fpl_archive::ArchivedSeason,
+    ) -> Result<fpl_seasons::FplSeason, String> {
+        let teams = archived
+            .teams
+            .iter()
+            .map(convert_team)
+            .collect::<Result<Vec<_>, _>>()?;
+        let players = convert_player_list(&archived.players)?;
+
+        let mut matches = fpl_fixtures::MatchList::new();
+        for api_fixture in &archived.fixtures {
+            matches.add_fixture(convert_fixture(api_fixture)?);
+        }
+
+        let mut player_gameweeks: HashMap<u32, Vec<fpl_player_summary::FplPlayerGameweekRecord>> =
+            HashMap::new();
+        for record in &archived.player_gameweeks {
+            player_gameweeks
+                .entry(record.element)
+                .or_default()
+                .push(convert_player_gameweek(record)?);
+        }
+        for records in player_gameweeks.values_mut() {
+            records.sort_by_key(|record| (record.gameweek, record.kickoff_time));
+        }
+
+        Ok(fpl_seasons::FplSeason {
+            season: archived.season.clone(),
+            teams,
+            players,
+            matches,
+            player_gameweeks,
+        })
+    }
+
+    pub fn convert_season_archive(
+        archive: &fpl_data::fpl_archive::SeasonArchive,
+    ) -> Result<fpl_seasons::FplSeasonHistory, String> {
+        let seasons = archive
+            .seasons
+            .iter()
+            .map(|(season, archived)| Ok((season.clone(), convert_archived_season(archived)?)))
+            .collect::<Result<_, String>>()?;
 
-        api_fixture.stats.iter().for_each(|stat| {
-            stat.h.iter().for_each(|player_id| {
-                match_stats.add_statistic(
-                    player_id.element,
-                    MatchStatistic::from(&stat.identifier).unwrap(),
-                    player_id.value,
-                );
-            });
-            stat.a.iter().for_each(|player_id| {
-                match_stats.add_statistic(
-                    player_id.element,
-                    MatchStatistic::from(&stat.identifier).unwrap(),
-                    player_id.value,
-                );
-            });
-        });
+        Ok(fpl_seasons::FplSeasonHistory { seasons })
+    }
 
-        Ok(fpl_fixtures::Match {
-            code: api_fixture.code,
-            event: api_fixture.event,
-            finished: api_fixture.finished,
-            finished_provisional: api_fixture.finished_provisional,
-            id: api_fixture.id,
-            kickoff_time: DateTime::parse_from_rfc3339(&api_fixture.kickoff_time)
-                .unwrap()
-                .into(),
-            minutes: api_fixture.minutes,
-            provisional_start_time: api_fixture.provisional_start_time,
-            started: api_fixture.started,
-            home_team_id: api_fixture.team_h,
-            away_team_id: api_fixture.team_a,
-            score: match_score,
-            stats: match_stats,
+    // The season a bootstrap-static document is for, like "2024-25", going by the first deadline
+    pub fn convert_season_name(bootstrap: &fpl_data::fpl_data::BootstrapStatic) -> Option<String> {
+        let first_deadline = parse_api_time(&bootstrap.events.first()?.deadline_time).ok()?;
+        let year = first_deadline.year();
+        Some(format!("{}-{:02}", year, (year + 1) % 100))
+    }
+
+    // The game settings with the points for each statistic, falling back to the season's
+    // official values for anything the settings do not mention
+    pub fn convert_game_settings(
+        bootstrap: &fpl_data::fpl_data::BootstrapStatic,
+    ) -> Result<fpl_game_settings::GameSettings, String> {
+        let api_settings = &bootstrap.game_settings;
+        let season_rules = convert_season_name(bootstrap)
+            .and_then(|season| ScoringRules::for_season(&season))
+            .unwrap_or_default();
+
+        Ok(fpl_game_settings::GameSettings {
+            squad: fpl_game_settings::SquadRules {
+                squad_size: api_settings.squad_squadsize,
+                starting_players: api_settings.squad_squadplay,
+                max_per_team: api_settings.squad_team_limit,
+                budget: api_settings.squad_total_spend,
+                transfers_cap: api_settings.transfers_cap,
+                max_extra_free_transfers: api_settings.max_extra_free_transfers,
+                sell_on_fee: api_settings.transfers_sell_on_fee,
+            },
+            scoring: convert_scoring_rules(&api_settings.scoring, season_rules)?,
+            h2h_points: fpl_game_settings::H2hPoints {
+                win: api_settings.league_points_h2h_win,
+                draw: api_settings.league_points_h2h_draw,
+                loss: api_settings.league_points_h2h_lose,
+            },
+            phases: bootstrap
+                .phases
+                .iter()
+                .map(|phase| fpl_game_settings::Phase {
+                    id: phase.id,
+                    name: phase.name.clone(),
+                    start_gameweek: phase.start_event,
+                    stop_gameweek: phase.stop_event,
+                    highest_score: phase.highest_score,
+                })
+                .collect(),
+            chips: bootstrap
+                .chips
+                .iter()
+                .map(|chip| fpl_game_settings::ChipWindow {
+                    chip: fpl_managers::Chip::from(&chip.name),
+                    number: chip.number,
+                    start_gameweek: chip.start_event,
+                    stop_gameweek: chip.stop_event,
+                })
+                .collect(),
         })
     }
 
-    pub fn convert_fixture_list(
-        api_fixture_list: &Vec<fpl_data::fpl_data::FplApiFixture>,
-    ) -> Result<fpl_fixtures::MatchList, &str> {
-        let mut fixture_list = fpl_fixtures::MatchList::new();
+    // Apply the scoring values from the game settings on top of a set of rules. Values are
+    // keyed by statistic identifier, with "long_play" and "short_play" for minutes.
+    pub fn convert_scoring_rules(
+        api_scoring: &BTreeMap<String, fpl_data::fpl_data::FplApiScoringValue>,
+        rules: ScoringRules,
+    ) -> Result<ScoringRules, String> {
+        apply_scoring_values(api_scoring, rules, "the game settings")
+    }
 
-        api_fixture_list.into_iter().for_each(|api_fixture| {
-            fixture_list.add_fixture(convert_fixture(api_fixture).unwrap())
-        });
+    // Shared with scoring rules files, with the source named in any error
+    pub(crate) fn apply_scoring_values(
+        values: &BTreeMap<String, fpl_data::fpl_data::FplApiScoringValue>,
+        mut rules: ScoringRules,
+        source: &str,
+    ) -> Result<ScoringRules, String> {
+        use fpl_data::fpl_data::FplApiScoringValue;
+
+        for (identifier, value) in values {
+            // Minutes are worth the same whatever the position
+            match (identifier.as_str(), value) {
+                ("long_play", FplApiScoringValue::All(points)) => {
+                    rules.long_play_points = *points;
+                    continue;
+                }
+                ("short_play", FplApiScoringValue::All(points)) => {
+                    rules.short_play_points = *points;
+                    continue;
+                }
+                ("long_play" | "short_play", _) => {
+                    return Err(format!("Expected a single value for {} in {}", identifier, source))
+                }
+                _ => (),
+            }
 
-        Ok(fixture_list)
+            // Settings for statistics the points engine does not track are left out
+            let Ok(statistic) = MatchStatistic::from(identifier) else {
+                continue;
+            };
+
+            let points = match value {
+                FplApiScoringValue::All(points) if statistic.goalkeeper_only() => {
+                    PositionPoints::goalkeeper(*points)
+                }
+                value => convert_position_points(identifier, value, source)?,
+            };
+
+            rules.points.insert(statistic, points);
+        }
+        Ok(rules)
+    }
+
+    pub(crate) fn convert_position_points(
+        identifier: &str,
+        value: &fpl_data::fpl_data::FplApiScoringValue,
+        source: &str,
+    ) -> Result<PositionPoints, String> {
+        use fpl_data::fpl_data::FplApiScoringValue;
+
+        match value {
+            FplApiScoringValue::All(points) => Ok(PositionPoints::all(*points)),
+            FplApiScoringValue::ByPosition(by_position) => {
+                let position_points = |position: &str| {
+                    by_position.get(position).cloned().ok_or_else(|| {
+                        format!("No {} value for {} in {}", position, identifier, source)
+                    })
+                };
+                Ok(PositionPoints {
+                    goalkeeper: position_points("GKP")?,
+                    defender: position_points("DEF")?,
+                    midfielder: position_points("MID")?,
+                    forward: position_points("FWD")?,
+                })
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use ::fpl_data::{fpl_client::FplClient, fpl_mock_server::MockSnapshots};
+
+    // A synthetic 2024/25 season after gameweek 2, read straight from disk
+    pub(crate) const SYNTHETIC_SEASON: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../fpl_data/test_data/synthetic-2024-25");
+
+    pub(crate) fn season_client() -> FplClient {
+        FplClient::new().with_transport(MockSnapshots::new(SYNTHETIC_SEASON))
+    }
 
     #[cfg(test)]
     mod test_statistics {
 
         use std::{collections::HashMap};
 
-        use crate::{fpl_conversions, fpl_match_stats::{self, MatchStatisticMap}, fpl_players::FplPlayer};
-        use fpl_data::fpl_data;
+        use crate::{
+            fpl_conversions,
+            fpl_match_stats::{self, MatchStatistic, MatchStatisticMap, ScoringRules},
+            fpl_players::FplPlayerList,
+        };
+        use ::fpl_data::fpl_client;
 
         #[tokio::test]
         async fn test_calculate_points() {
-            let api_players = fpl_data::get_players()
+            let api_players = super::season_client()
+                .get_players()
                 .await
                 .expect("Failed to get players");
 
@@ -794,8 +3268,10 @@ mod tests {
             );
             match_statistic.add_statistic(player.id, fpl_match_stats::MatchStatistic::Assists, 2);
             match_statistic.add_statistic(player.id, fpl_match_stats::MatchStatistic::Minutes, 72);
+            match_statistic.add_statistic(player.id, fpl_match_stats::MatchStatistic::Bps, 30);
 
-            let points_from_stats = match_statistic.get_player_points_from_stats(&player_list);
+            let points_from_stats = match_statistic
+                .get_player_points_from_stats(&player_list, &ScoringRules::default()).unwrap();
 
             let first_player_points = points_from_stats[0];
 
@@ -803,69 +3279,410 @@ mod tests {
             assert_eq!(first_player_points.1, 15);
         }
 
+        #[test]
+        fn test_scoring_rules_from_game_settings() {
+            let mut json: serde_json::Value = serde_json::from_str(include_str!(
+                "../../fpl_data/test_data/synthetic-2024-25/bootstrap-static.json"
+            ))
+            .unwrap();
+            json["game_settings"]["scoring"] = serde_json::json!({
+                "goals_scored": {"GKP": 6, "DEF": 6, "MID": 5, "FWD": 5},
+                "long_play": 3,
+                "clean_sheets": {"GKP": 4, "DEF": 4, "MID": 1, "FWD": 0},
+                "saves": 1,
+            });
+            let bootstrap = ::fpl_data::fpl_data::BootstrapStatic::from_json(json).unwrap();
+            let settings = fpl_conversions::convert_game_settings(&bootstrap).unwrap();
+            let player_list = fpl_conversions::convert_player_list(&bootstrap.elements).unwrap();
+            let haaland = player_list.get_player(&351).unwrap();
+
+            let mut match_statistic = MatchStatisticMap::new();
+            match_statistic.add_statistic(351, fpl_match_stats::MatchStatistic::GoalsScored, 1);
+            match_statistic.add_statistic(351, fpl_match_stats::MatchStatistic::Assists, 2);
+            match_statistic.add_statistic(351, fpl_match_stats::MatchStatistic::Minutes, 72);
+            match_statistic.add_statistic(351, fpl_match_stats::MatchStatistic::Saves, 7);
+            match_statistic.add_statistic(351, fpl_match_stats::MatchStatistic::Bps, 30);
+
+            // A forward's goal is worth 5 and long play 3, on top of the usual assists and bonus.
+            // Saves only ever score for goalkeepers.
+            let points = match_statistic.get_player_points_from_stats(&player_list, &settings.scoring).unwrap();
+            assert_eq!(points, vec![(haaland, 5 + 6 + 3 + 3)]);
+            assert_eq!(
+                settings.scoring.points[&fpl_match_stats::MatchStatistic::Saves],
+                fpl_match_stats::PositionPoints::goalkeeper(1)
+            );
+            let points =
+                match_statistic.get_player_points_from_stats(&player_list, &ScoringRules::default()).unwrap();
+            assert_eq!(points, vec![(haaland, 4 + 6 + 2 + 3)]);
+
+            assert_eq!(
+                fpl_conversions::convert_scoring_rules(
+                    &serde_json::from_str(r#"{"long_play": {"GKP": 2, "DEF": 2, "MID": 2, "FWD": 2}}"#)
+                        .unwrap(),
+                    ScoringRules::default()
+                ),
+                Err(String::from("Expected a single value for long_play in the game settings"))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_points_from_live_gameweek() {
+            let transport = fpl_client::InMemoryTransport::new()
+                .with_json(
+                    "bootstrap-static/",
+                    include_str!("../../fpl_data/test_data/bootstrap-static.json"),
+                )
+                .with_json(
+                    "event/1/live/",
+                    include_str!("../../fpl_data/test_data/event-1-live.json"),
+                );
+            let client = fpl_client::FplClient::new().with_transport(transport);
+
+            let player_list =
+                fpl_conversions::convert_player_list(&client.get_players().await.unwrap()).unwrap();
+            let api_live = client.get_live_gameweek(1).await.unwrap();
+            let mut live = fpl_conversions::convert_live_gameweek(1, &api_live).unwrap();
+
+            assert_eq!(live.fixture_ids(), vec![1, 4, 10]);
+
+            let points = live
+                .get_fixture_stats_mut(10)
+                .unwrap()
+                .get_player_points_from_stats(&player_list, &ScoringRules::default()).unwrap();
+            assert_eq!(points.len(), 1);
+            assert_eq!(points[0].0.id, 351);
+            assert_eq!(Some(points[0].1), live.official_points(351));
+
+            // Gabriel played twice, so each fixture only gets its share of his stats
+            let mut gabriel_points = Vec::new();
+            for fixture_id in [1, 4] {
+                let points = live
+                    .get_fixture_stats_mut(fixture_id)
+                    .unwrap()
+                    .get_player_points_from_stats(&player_list, &ScoringRules::default()).unwrap();
+                assert_eq!(points[0].0.id, 3);
+                gabriel_points.push(points[0].1);
+            }
+            assert_eq!(gabriel_points, vec![13, 2]);
+            assert_eq!(Some(gabriel_points.iter().sum()), live.official_points(3));
+        }
+
         #[tokio::test]
         async fn validate_points_calculation_in_matches() {
-            
-            let api_players = fpl_data::get_players()
+            let client = super::season_client();
+
+            let api_players = client.get_players()
                 .await
                 .expect("Failed to get players");
 
             let player_list = fpl_conversions::convert_player_list(&api_players).unwrap();
 
-            let api_games = fpl_data::get_fixtures()
+            let api_games = client.get_fixtures()
                 .await
                 .expect("Failed to get matches");
 
-            let match_list = fpl_conversions::convert_fixture_list(&api_games).unwrap();
+            let mut match_list = fpl_conversions::convert_fixture_list(&api_games).unwrap();
 
-            // Perform our own calculation to get the points in the match
-            // Let's figure this out later
-            let match_stats: Vec<MatchStatisticMap> = match_list.match_list.into_iter().map(|fixture| fixture.stats).collect();
-            let calculated_points: Vec<Vec<(&FplPlayer, i32)>> = match_stats.into_iter().map(|mut stats| stats.get_player_points_from_stats(&player_list)).collect();
-            
-            // Now we need to add this up over the range of games
+            // The season's totals come from generate.py, which scores with the same rules, so this
+            // checks that fixtures, live stats and season totals fit together rather than that
+            // the rules match FPL's. test_official_scoring covers the rules.
+            let rules = ScoringRules::for_season("2024-25").unwrap();
             let mut player_to_points_map: HashMap<u32, i32> = HashMap::new();
 
-            let mut assign_player_points = |player: &FplPlayer, points: i32| {
-                let player_node = player_to_points_map.entry(player.id).or_default();
-                *player_node += points;
-            };
+            for gameweek in 1..=2 {
+                let api_live = client.get_live_gameweek(gameweek).await.expect("Failed to get live gameweek");
+                let live = fpl_conversions::convert_live_gameweek(gameweek, &api_live).unwrap();
 
-            let assign_game_points = |points_in_game: Vec<(&FplPlayer, i32)>| {
-                points_in_game.into_iter().for_each(|(player, points)| assign_player_points(player, points));
-            };
-            
-            calculated_points.into_iter().for_each(assign_game_points);
+                for fixture in match_list.match_list.iter_mut().filter(|fixture| fixture.event == Some(gameweek)) {
+                    fixture.add_live_statistics(&live);
 
-            // player_to_points_map should now contain the right points for the season so far
+                    for (player, points) in fixture.points_for_match(&player_list, &rules).unwrap() {
+                        // Everyone plays once a gameweek, so each match is the whole gameweek
+                        assert_eq!(Some(points), live.official_points(player.id));
+                        *player_to_points_map.entry(player.id).or_default() += points;
+                    }
+                }
+            }
+
+            // player_to_points_map should now add up to the season totals in bootstrap-static
+            assert_eq!(player_to_points_map.len(), player_list.player_list.len());
             let check_points = |(player_id, points): (&u32, &i32)| {
                 let player = player_list.get_player(&player_id).unwrap();
-                println!("Player {} has {} points, and we think he's got {} points", player.name.display_name, player.points_record.total_points, *points);
-                // assert_eq!(player_list.get_player(&player_id).unwrap().points_record.total_points, *points);
+                assert_eq!(player.points_record.total_points, *points, "{}", player.name.display_name);
             };
             player_to_points_map.iter().for_each(check_points);
+        }
+
+        #[test]
+        fn test_official_scoring() {
+            let (raya, gabriel, saka) = (1, 3, 2);
+            let haaland = 351;
+
+            let points = scored(
+                &[
+                    // The keeper played the whole match, conceded three and saved a penalty
+                    (raya, MatchStatistic::Minutes, 90),
+                    (raya, MatchStatistic::GoalsConceded, 3),
+                    (raya, MatchStatistic::Saves, 7),
+                    (raya, MatchStatistic::PenaltiesSaved, 1),
+                    (raya, MatchStatistic::Bps, 30),
+                    // A clean sheet does not count for a defender who came off before the hour
+                    (gabriel, MatchStatistic::Minutes, 59),
+                    (gabriel, MatchStatistic::CleanSheets, 1),
+                    (gabriel, MatchStatistic::OwnGoals, 1),
+                    (gabriel, MatchStatistic::Bps, 30),
+                    // Outfield players get nothing for saves or goals conceded
+                    (saka, MatchStatistic::Minutes, 60),
+                    (saka, MatchStatistic::CleanSheets, 1),
+                    (saka, MatchStatistic::GoalsConceded, 3),
+                    (saka, MatchStatistic::Saves, 3),
+                    (saka, MatchStatistic::YellowCards, 1),
+                    (saka, MatchStatistic::Bps, 12),
+                    (haaland, MatchStatistic::Minutes, 90),
+                    (haaland, MatchStatistic::PenaltiesMissed, 1),
+                    (haaland, MatchStatistic::RedCards, 1),
+                    (haaland, MatchStatistic::Bps, 5),
+                ],
+                &ScoringRules::default(),
+            );
+
+            // The tie for first gives both players three bonus points, and the next one bonus point
+            assert_eq!(
+                points,
+                vec![
+                    (raya, 2 - 1 + 2 + 5 + 3),
+                    (saka, 2 + 1 - 1 + 1),
+                    (gabriel, 1 - 2 + 3),
+                    (haaland, 2 - 2 - 3),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_season_scoring_rules() {
+            let (raya, gabriel, saka) = (1, 3, 2);
+            let haaland = 351;
+
+            let stats = [
+                (raya, MatchStatistic::GoalsScored, 1),
+                (raya, MatchStatistic::DefensiveContribution, 15),
+                (gabriel, MatchStatistic::DefensiveContribution, 10),
+                (saka, MatchStatistic::DefensiveContribution, 11),
+                (haaland, MatchStatistic::DefensiveContribution, 25),
+            ];
+            let points_in_season =
+                |season: &str| scored(&stats, &ScoringRules::for_season(season).unwrap());
+
+            // Defenders need ten defensive actions and everyone else outfield twelve, but only
+            // once per match
+            assert_eq!(
+                points_in_season("2025-26"),
+                vec![(raya, 10), (saka, 0), (gabriel, 2), (haaland, 2)]
+            );
+            assert_eq!(
+                points_in_season("2024-25"),
+                vec![(raya, 10), (saka, 0), (gabriel, 0), (haaland, 0)]
+            );
+            // Before 2024/25 a goalkeeper's goal was worth six
+            assert_eq!(
+                points_in_season("2023-24"),
+                vec![(raya, 6), (saka, 0), (gabriel, 0), (haaland, 0)]
+            );
+
+            assert_eq!(ScoringRules::default(), ScoringRules::for_season("2025-26").unwrap());
+            assert!(fpl_match_stats::SCORING_SEASONS
+                .iter()
+                .all(|season| ScoringRules::for_season(season).is_some()));
+            assert_eq!(ScoringRules::for_season("1992-93"), None);
+        }
+
+        #[test]
+        fn test_scoring_rules_from_file() {
+            let rules = ScoringRules::load(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../fpl_data/test_data/house-rules.toml"
+            ))
+            .unwrap();
+            let json_rules = ScoringRules::from_json(
+                r#"{
+                    "season": "2024-25",
+                    "expected_goals_per_point": 0.5,
+                    "points": {
+                        "clean_sheets": {"GKP": 4, "DEF": 4, "MID": 4, "FWD": 0},
+                        "expected_goals": 1,
+                        "bonus": 0
+                    }
+                }"#,
+            )
+            .unwrap();
+            assert_eq!(rules, json_rules);
+            assert_eq!(rules.expected_goals_per_point, 50);
+
+            let (saka, haaland) = (2, 351);
+            let points = scored(
+                &[
+                    (saka, MatchStatistic::Minutes, 90),
+                    (saka, MatchStatistic::CleanSheets, 1),
+                    (saka, MatchStatistic::ExpectedGoals, 83),
+                    (saka, MatchStatistic::Bps, 30),
+                    (haaland, MatchStatistic::Minutes, 90),
+                    (haaland, MatchStatistic::GoalsScored, 1),
+                    (haaland, MatchStatistic::ExpectedGoals, 160),
+                    (haaland, MatchStatistic::Bps, 20),
+                ],
+                &rules,
+            );
+            assert_eq!(points, vec![(saka, 2 + 4 + 1), (haaland, 2 + 4 + 3)]);
+
+            assert_eq!(
+                ScoringRules::from_toml("[points]\nexpected_assists = 1"),
+                Err(String::from("Unknown statistic expected_assists in the scoring rules"))
+            );
+            assert_eq!(
+                ScoringRules::from_toml("season = \"1992-93\""),
+                Err(String::from("No built-in scoring rules for 1992-93"))
+            );
+            assert_eq!(
+                ScoringRules::from_json(r#"{"defensive_contribution_threshold": {"DEF": 10}}"#),
+                Err(String::from(
+                    "No GKP value for defensive_contribution_threshold in the scoring rules"
+                ))
+            );
+            assert!(ScoringRules::from_toml("bonus = 0").unwrap_err().starts_with("Invalid scoring rules"));
+        }
+
+        #[tokio::test]
+        async fn test_points_breakdown() {
+            let client = super::season_client();
+            let player_list =
+                fpl_conversions::convert_player_list(&client.get_players().await.unwrap()).unwrap();
+            let mut match_list =
+                fpl_conversions::convert_fixture_list(&client.get_fixtures().await.unwrap()).unwrap();
+            let api_live = client.get_live_gameweek(1).await.unwrap();
+            let live = fpl_conversions::convert_live_gameweek(1, &api_live).unwrap();
+            let rules = ScoringRules::for_season("2024-25").unwrap();
+
+            let mut breakdowns = Vec::new();
+            for fixture in match_list.match_list.iter_mut().filter(|fixture| fixture.event == Some(1)) {
+                fixture.add_live_statistics(&live);
+                breakdowns.extend(fixture.points_breakdown(&player_list, &rules).unwrap());
+            }
+            assert_eq!(breakdowns.len(), api_live.elements.len());
+
+            // Every statistic that scored matches the explain in the synthetic live data. That was
+            // generated with the same rules, so this checks the shape of the breakdown, not FPL.
+            for element in &api_live.elements {
+                let breakdown = breakdowns
+                    .iter()
+                    .find(|breakdown| breakdown.element == element.id)
+                    .unwrap();
+                assert_eq!(breakdown.fixture, element.explain[0].fixture);
+                assert_eq!(breakdown.total_points, element.stats.total_points);
+
+                let scored = |stats: Vec<(String, i32)>| -> HashMap<String, i32> {
+                    stats.into_iter().filter(|(_, points)| *points != 0).collect()
+                };
+                let official = scored(
+                    element.explain[0]
+                        .stats
+                        .iter()
+                        .map(|stat| (stat.identifier.clone(), stat.points))
+                        .collect(),
+                );
+                let ours = scored(
+                    breakdown
+                        .stats
+                        .iter()
+                        .map(|stat| {
+                            let identifier = serde_json::to_value(stat.identifier).unwrap();
+                            (identifier.as_str().unwrap().to_string(), stat.points)
+                        })
+                        .collect(),
+                );
+                assert_eq!(ours, official);
+            }
+
+            let haaland = breakdowns.iter().find(|breakdown| breakdown.element == 351).unwrap();
+            let json = serde_json::to_value(haaland).unwrap();
+            assert_eq!(json["element"], 351);
+            assert!(json["stats"].as_array().unwrap().contains(&serde_json::json!({
+                "identifier": "minutes",
+                "value": 90,
+                "points": 2,
+                "rule": "2 points for 60 minutes or more",
+            })));
+            let goals = haaland.statistic(MatchStatistic::GoalsScored).unwrap();
+            assert_eq!((goals.value, goals.points), (2, 8));
+            assert_eq!(goals.rule, "4 points a goal for a forward");
+            assert_eq!(
+                haaland.statistic(MatchStatistic::CleanSheets).unwrap().rule,
+                "0 points a clean sheet for a forward"
+            );
+        }
+
+        fn season_players() -> FplPlayerList {
+            let bootstrap: serde_json::Value = serde_json::from_str(include_str!(
+                "../../fpl_data/test_data/synthetic-2024-25/bootstrap-static.json"
+            ))
+            .unwrap();
+            let bootstrap = ::fpl_data::fpl_data::BootstrapStatic::from_json(bootstrap).unwrap();
+            fpl_conversions::convert_player_list(&bootstrap.elements).unwrap()
+        }
+
+        // Each player's points from one match's statistics, ordered by player id
+        fn scored(stats: &[(u32, MatchStatistic, i32)], rules: &ScoringRules) -> Vec<(u32, i32)> {
+            let players = season_players();
+            let mut match_statistic = MatchStatisticMap::new();
+            for &(player_id, statistic, value) in stats {
+                match_statistic.add_statistic(player_id, statistic, value);
+            }
 
+            let mut points: Vec<(u32, i32)> = match_statistic
+                .get_player_points_from_stats(&players, rules)
+                .unwrap()
+                .into_iter()
+                .map(|(player, points)| (player.id, points))
+                .collect();
+            points.sort();
+            points
         }
     }
 
     #[cfg(test)]
     mod test_conversions {
 
+        use std::{
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Arc,
+            },
+            time::Duration,
+        };
+
+        use ::fpl_data::fpl_client;
         use approx::assert_relative_eq;
-        use fpl_data::fpl_data;
+        use chrono::{TimeZone, Utc};
 
-        use crate::{fpl_conversions, fpl_fixtures, fpl_positions, fpl_teams};
+        use crate::{
+            fpl_changes, fpl_conversions, fpl_fixtures, fpl_game_settings, fpl_leagues, fpl_managers,
+            fpl_match_stats::{MatchStatistic, ScoringRules}, fpl_positions, fpl_registry, fpl_teams,
+        };
 
         #[tokio::test]
         async fn test_get_events() {
-            let events = fpl_data::get_events().await.expect("Failed to get events");
+            let events = super::season_client()
+                .get_events()
+                .await
+                .expect("Failed to get events");
 
-            let _ = events.first().unwrap();
+            assert_eq!(events.len(), 38);
+            assert!(events[1].is_current);
         }
 
         #[tokio::test]
         async fn test_get_positions() {
-            let api_positions = fpl_data::get_positions()
+            let api_positions = super::season_client()
+                .get_positions()
                 .await
                 .expect("Failed to get positions");
 
@@ -882,7 +3699,8 @@ mod tests {
 
         #[tokio::test]
         async fn test_get_players() {
-            let api_players = fpl_data::get_players()
+            let api_players = super::season_client()
+                .get_players()
                 .await
                 .expect("Failed to get players");
 
@@ -891,9 +3709,25 @@ mod tests {
             assert_eq!(players.get_player(&3).unwrap().name.display_name, "Gabriel");
         }
 
+        #[tokio::test]
+        async fn test_unknown_element_types_are_errors() {
+            let mut api_players = super::season_client()
+                .get_players()
+                .await
+                .expect("Failed to get players");
+
+            // 2024/25 listed its assistant managers as element type 5
+            for element_type in [0, 5] {
+                api_players[0].element_type = element_type;
+                assert!(fpl_conversions::convert_player(&api_players[0]).is_err());
+                assert!(fpl_conversions::convert_player_list(&api_players).is_err());
+            }
+        }
+
         #[tokio::test]
         async fn test_stats_per_90() {
-            let api_players = fpl_data::get_players()
+            let api_players = super::season_client()
+                .get_players()
                 .await
                 .expect("Failed to get players");
             let player_indices = vec![0, 10, 25, 50];
@@ -902,7 +3736,7 @@ mod tests {
             for player_idx in player_indices {
                 let api_player = &api_players[player_idx];
 
-                let player = fpl_conversions::convert_player(api_player);
+                let player = fpl_conversions::convert_player(api_player).unwrap();
                 let calculated_stats = player.get_stats_per_90();
 
                 assert_relative_eq!(player.stats_per_90, calculated_stats, epsilon = 1e-2);
@@ -911,7 +3745,10 @@ mod tests {
 
         #[tokio::test]
         async fn test_get_teams() {
-            let json_teams = fpl_data::get_teams().await.expect("Failed to get teams");
+            let json_teams = super::season_client()
+                .get_teams()
+                .await
+                .expect("Failed to get teams");
 
             let teams: Vec<fpl_teams::FplTeam> = json_teams
                 .iter()
@@ -925,9 +3762,534 @@ mod tests {
             assert_eq!(teams[0].table_data.played, 0);
         }
 
+        #[tokio::test]
+        async fn test_convert_player_summary() {
+            let transport = fpl_client::InMemoryTransport::new()
+                .with_json(
+                    "bootstrap-static/",
+                    include_str!("../../fpl_data/test_data/bootstrap-static.json"),
+                )
+                .with_json(
+                    "element-summary/351/",
+                    include_str!("../../fpl_data/test_data/element-summary-351.json"),
+                );
+            let client = fpl_client::FplClient::new().with_transport(transport);
+
+            let players =
+                fpl_conversions::convert_player_list(&client.get_players().await.unwrap()).unwrap();
+            let api_summary = client.get_element_summary(351).await.unwrap();
+            let summary = fpl_conversions::convert_player_summary(351, &api_summary).unwrap();
+
+            let haaland = summary.player(&players).unwrap();
+            assert_eq!(haaland.name.display_name, "Haaland");
+            assert_eq!(haaland.form.selected_by_percent, Some(31.4));
+            assert_eq!(haaland.form.expected_points_next, Some(4.5));
+            assert_eq!(haaland.ict.ict_index, Some(96.8));
+            assert_eq!(haaland.expected_stats.expected_goals, Some(4.12));
+            assert_eq!(summary.points_in_gameweek(2), 14);
+            assert_eq!(summary.history[0].stats.goals_scored, 1);
+            assert_eq!(summary.next_fixture().unwrap().opponent_team_id, 10);
+            assert_eq!(summary.fixtures[1].opponent_team_id, 5);
+            assert_eq!(summary.past_season("2023/24").unwrap().total_points, 217);
+        }
+
+        #[tokio::test]
+        async fn test_convert_manager() {
+            let transport = fpl_client::InMemoryTransport::new()
+                .with_json(
+                    "bootstrap-static/",
+                    include_str!("../../fpl_data/test_data/bootstrap-static.json"),
+                )
+                .with_json("entry/1/", include_str!("../../fpl_data/test_data/entry-1.json"))
+                .with_json(
+                    "entry/1/history/",
+                    include_str!("../../fpl_data/test_data/entry-1-history.json"),
+                )
+                .with_json(
+                    "entry/1/event/2/picks/",
+                    include_str!("../../fpl_data/test_data/entry-1-event-2-picks.json"),
+                )
+                .with_json(
+                    "entry/1/transfers/",
+                    include_str!("../../fpl_data/test_data/entry-1-transfers.json"),
+                );
+            let client = fpl_client::FplClient::new().with_transport(transport);
+
+            let manager = fpl_conversions::convert_manager(&client.get_entry(1).await.unwrap());
+            assert_eq!(manager.team_name, "Expected Toulouse");
+            assert_eq!(manager.player_name, "Sam Taylor");
+            assert_eq!(manager.classic_league_ids, vec![314, 276]);
+
+            let history = fpl_conversions::convert_manager_history(
+                1,
+                &client.get_entry_history(1).await.unwrap(),
+            )
+            .unwrap();
+            assert_eq!(history.gameweek(2).unwrap().total_points, 141);
+            assert_eq!(history.chip_played_in(2), Some(&fpl_managers::Chip::BenchBoost));
+
+            let players =
+                fpl_conversions::convert_player_list(&client.get_players().await.unwrap()).unwrap();
+            let squad = fpl_conversions::convert_manager_squad(
+                1,
+                2,
+                &client.get_entry_picks(1, 2).await.unwrap(),
+            );
+            let rules = fpl_game_settings::SquadRules::default();
+            assert_eq!(squad.starting_xi(&rules).len(), 11);
+            assert_eq!(squad.bench(&rules).len(), 4);
+            assert_eq!(squad.captain().unwrap().player_id, 351);
+            assert_eq!(squad.vice_captain().unwrap().player_id, 3);
+            assert_eq!(squad.active_chip, Some(fpl_managers::Chip::BenchBoost));
+            assert_eq!(squad.automatic_subs, vec![(70, 45)]);
+            let resolved = squad.players(&players);
+            assert_eq!(resolved[1].unwrap().name.display_name, "Gabriel");
+            assert!(resolved[0].is_none());
+
+            let transfers = fpl_conversions::convert_transfers(
+                &client.get_entry_transfers(1).await.unwrap(),
+            )
+            .unwrap();
+            assert_eq!(transfers[0].player_in, 400);
+            assert_eq!(transfers[0].player_out, 401);
+        }
+
+        #[tokio::test]
+        async fn test_league_fans_out_to_members() {
+            let transport = fpl_client::InMemoryTransport::new()
+                .with_json(
+                    "leagues-classic/314/standings/?page_standings=1",
+                    include_str!("../../fpl_data/test_data/leagues-classic-314-standings-1.json"),
+                )
+                .with_json(
+                    "leagues-classic/314/standings/?page_standings=2",
+                    include_str!("../../fpl_data/test_data/leagues-classic-314-standings-2.json"),
+                )
+                .with_json(
+                    "leagues-h2h/1234/standings/?page_standings=1",
+                    include_str!("../../fpl_data/test_data/leagues-h2h-1234-standings-1.json"),
+                )
+                .with_json(
+                    "leagues-h2h-matches/league/1234/?page=1",
+                    include_str!("../../fpl_data/test_data/leagues-h2h-matches-1234-1.json"),
+                )
+                .with_json("entry/1/", include_str!("../../fpl_data/test_data/entry-1.json"))
+                .with_json("entry/2/", include_str!("../../fpl_data/test_data/entry-2.json"));
+            let client = fpl_client::FplClient::new().with_transport(transport);
+
+            let classic =
+                fpl_conversions::convert_classic_league(&client.get_classic_league(314).await.unwrap());
+            assert_eq!(classic.manager_ids(), vec![2, 1, 3]);
+            assert_eq!(classic.leader().unwrap().team_name, "Route One FC");
+            assert_eq!(classic.member(1).unwrap().gameweek_points, Some(64));
+
+            let h2h = fpl_conversions::convert_h2h_league(
+                &client.get_h2h_league(1234).await.unwrap(),
+                &client.get_h2h_matches(1234).await.unwrap(),
+            );
+            assert_eq!(h2h.kind, fpl_leagues::LeagueKind::HeadToHead);
+            assert_eq!(h2h.member(2).unwrap().h2h_record.as_ref().unwrap().won, 1);
+            assert_eq!(h2h.matches_in_gameweek(1)[0].winner, None);
+
+            let managers = h2h.fetch_member_entries(&client).await.unwrap();
+            let names: Vec<&str> = managers.iter().map(|m| m.player_name.as_str()).collect();
+            assert_eq!(names, vec!["Alex Morgan", "Sam Taylor"]);
+
+            // Member 3 has no entry, so the fan-out reports the failed request
+            assert!(classic.fetch_member_entries(&client).await.is_err());
+        }
+
+        // Counts the requests in flight, and panics for any manager over 100
+        #[derive(Default)]
+        struct EntryProbe {
+            in_flight: AtomicUsize,
+            most_in_flight: AtomicUsize,
+        }
+
+        #[async_trait::async_trait]
+        impl fpl_client::FplTransport for EntryProbe {
+            async fn send(
+                &self,
+                request: fpl_client::FplRequest,
+            ) -> Result<fpl_client::FplResponse, ::fpl_data::fpl_data::FplDataError> {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.most_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let manager_id: u32 = request
+                    .endpoint
+                    .trim_matches(|c: char| !c.is_ascii_digit())
+                    .parse()
+                    .unwrap();
+                assert!(manager_id <= 100, "No entry for manager {}", manager_id);
+                Ok(fpl_client::FplResponse::new(
+                    200,
+                    include_str!("../../fpl_data/test_data/entry-1.json"),
+                ))
+            }
+        }
+
+        #[tokio::test]
+        async fn test_league_fan_out_is_bounded() {
+            let league = |manager_ids: std::ops::RangeInclusive<u32>| fpl_leagues::League {
+                id: 1,
+                name: String::from("Big league"),
+                kind: fpl_leagues::LeagueKind::Classic,
+                members: manager_ids
+                    .map(|manager_id| fpl_leagues::LeagueMember {
+                        manager_id,
+                        team_name: String::new(),
+                        player_name: String::new(),
+                        rank: manager_id,
+                        last_rank: manager_id,
+                        total: 0,
+                        gameweek_points: None,
+                        h2h_record: None,
+                    })
+                    .collect(),
+                matches: Vec::new(),
+            };
+
+            let probe = Arc::new(EntryProbe::default());
+            let client = fpl_client::FplClient::new().with_transport(probe.clone());
+            let managers = league(1..=40).fetch_member_entries(&client).await.unwrap();
+            assert_eq!(managers.len(), 40);
+            assert!(
+                probe.most_in_flight.load(Ordering::SeqCst) <= fpl_leagues::DEFAULT_MEMBER_CONCURRENCY
+            );
+
+            // The client's own limit wins when it sets one
+            let probe = Arc::new(EntryProbe::default());
+            let client = fpl_client::FplClient::new()
+                .with_transport(probe.clone())
+                .with_max_concurrency(2)
+                .unwrap();
+            league(1..=10).fetch_member_entries(&client).await.unwrap();
+            assert_eq!(probe.most_in_flight.load(Ordering::SeqCst), 2);
+
+            // A request that panics is reported as an error instead of panicking the caller
+            let error = league(95..=105).fetch_member_entries(&client).await.unwrap_err();
+            assert!(matches!(error, ::fpl_data::fpl_data::FplDataError::Transport { .. }));
+        }
+
+        #[tokio::test]
+        async fn test_convert_gameweeks() {
+            let transport = fpl_client::InMemoryTransport::new().with_json(
+                "bootstrap-static/",
+                include_str!("../../fpl_data/test_data/bootstrap-static.json"),
+            );
+            let client = fpl_client::FplClient::new().with_transport(transport);
+            let bootstrap = client.get_bootstrap_static().await.unwrap();
+
+            let players = fpl_conversions::convert_player_list(&bootstrap.elements).unwrap();
+            let gameweeks = fpl_conversions::convert_gameweek_list(bootstrap.events()).unwrap();
+
+            let current = gameweeks.current().unwrap();
+            assert_eq!(current.id, 1);
+            assert_eq!(current.chip_play_count(&fpl_managers::Chip::TripleCaptain), 210000);
+            assert_eq!(current.chip_play_count(&fpl_managers::Chip::Wildcard), 0);
+            assert_eq!(current.top_player(&players).unwrap().name.display_name, "Haaland");
+            assert_eq!(current.top_element_points, Some(14));
+            assert_eq!(
+                current.most_captained_player(&players).unwrap().name.display_name,
+                "Haaland"
+            );
+
+            let deadline = Utc.with_ymd_and_hms(2024, 8, 24, 10, 0, 0).unwrap();
+            assert_eq!(gameweeks.next().unwrap().id, 2);
+            assert_eq!(gameweeks.next_deadline(), Some(deadline));
+            assert!(gameweeks.next().unwrap().top_player(&players).is_none());
+            assert_eq!(
+                gameweeks
+                    .next_deadline_after(Utc.with_ymd_and_hms(2024, 8, 1, 0, 0, 0).unwrap())
+                    .unwrap()
+                    .id,
+                1
+            );
+            assert!(gameweeks.next_deadline_after(deadline).is_none());
+        }
+
+        #[test]
+        fn test_convert_bootstrap_changes() {
+            let json: serde_json::Value = serde_json::from_str(include_str!(
+                "../../fpl_data/test_data/synthetic-2024-25/bootstrap-static.json"
+            ))
+            .unwrap();
+            let mut later = json.clone();
+            let elements = later["elements"].as_array_mut().unwrap();
+            // Saka is injured and drops in price, Haaland rises
+            elements[1]["now_cost"] = 99.into();
+            elements[1]["status"] = "i".into();
+            elements[1]["chance_of_playing_next_round"] = 0.into();
+            elements[1]["news"] = "Hamstring injury - Expected back 14 Sep".into();
+            elements[1]["news_added"] = "2024-09-02T17:00:05.893154Z".into();
+            let haaland = elements.iter_mut().find(|player| player["id"] == 351).unwrap();
+            haaland["now_cost"] = 151.into();
+            haaland["penalties_order"] = 2.into();
+
+            let before = fpl_data::fpl_data::BootstrapStatic::from_json(json).unwrap();
+            let after = fpl_data::fpl_data::BootstrapStatic::from_json(later).unwrap();
+            let changes = fpl_conversions::convert_bootstrap_changes(&before, &after).unwrap();
+
+            let players = fpl_conversions::convert_player_list(&after.elements).unwrap();
+            let price_changes = changes.price_changes();
+            assert_eq!(price_changes.len(), 2);
+            assert_eq!(price_changes[0].player(&players).unwrap().name.display_name, "Saka");
+            assert_eq!(
+                price_changes[1].event,
+                fpl_changes::PlayerEvent::PriceRise { from: 150, to: 151 }
+            );
+
+            let unavailable = changes.newly_unavailable();
+            assert_eq!(unavailable.len(), 1);
+            assert_eq!(unavailable[0].player_id, 2);
+            assert_eq!(
+                changes.for_player(2)[3],
+                &fpl_changes::PlayerEvent::NewsPosted {
+                    news: String::from("Hamstring injury - Expected back 14 Sep"),
+                    added: Some(Utc.with_ymd_and_hms(2024, 9, 2, 17, 0, 5).unwrap()
+                        + chrono::Duration::microseconds(893154)),
+                }
+            );
+            assert_eq!(
+                changes.for_player(351)[1],
+                &fpl_changes::PlayerEvent::SetPieceOrderChanged {
+                    set_piece: fpl_changes::SetPiece::Penalties,
+                    from: Some(1),
+                    to: Some(2),
+                }
+            );
+            assert!(fpl_conversions::convert_bootstrap_changes(&after, &after)
+                .unwrap()
+                .is_empty());
+        }
+
+        #[test]
+        fn test_convert_season_archive() {
+            let archive = fpl_data::fpl_archive::SeasonArchive::load(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../fpl_data/test_data/archive"
+            ))
+            .unwrap();
+            let history = fpl_conversions::convert_season_archive(&archive).unwrap();
+
+            let season = history.season("2022-23").unwrap();
+            assert_eq!(season.team(9).unwrap().name, "Leicester");
+            assert_eq!(season.players.get_player(&318).unwrap().name.display_name, "Haaland");
+            assert_eq!(season.matches.match_list.len(), 2);
+            assert_eq!(season.matches.match_list[1].away_team_id, 11);
+            assert_eq!(season.points_in_gameweek(318, 1), 13);
+            assert_eq!(season.player_gameweeks(318)[0].stats.goals_scored, 2);
+            assert!(season.player_gameweeks(1).is_empty());
+            assert_eq!(season.scoring_rules(), ScoringRules::for_season("2022-23"));
+
+            // The same player has a different id the next season
+            let season = history.season("2023-24").unwrap();
+            assert!(season.players.get_player(&318).is_none());
+            let rice = &season.player_gameweeks(20)[0];
+            assert_eq!(rice.kickoff_time, Utc.with_ymd_and_hms(2023, 8, 12, 14, 0, 0).unwrap());
+            assert_eq!((rice.opponent_team_id, rice.was_home, rice.value), (13, false, 65));
+        }
+
+        #[tokio::test]
+        async fn test_registry_links_seasons() {
+            let archive = fpl_data::fpl_archive::SeasonArchive::load(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../fpl_data/test_data/archive"
+            ))
+            .unwrap();
+            let history = fpl_conversions::convert_season_archive(&archive).unwrap();
+            let mut registry = fpl_registry::FplRegistry::from_history(&history);
+
+            // Haaland keeps his code while his id changes
+            assert_eq!(registry.player_code("2022-23", 318), Some(223094));
+            assert_eq!(registry.same_player("2022-23", 318, "2023-24"), Some(355));
+            assert_eq!(registry.same_team("2022-23", 11, "2023-24"), Some(13));
+
+            // Leicester went down and Luton came up
+            assert_eq!(registry.relegated_teams("2023-24"), vec![13]);
+            assert_eq!(registry.promoted_teams("2023-24"), vec![102]);
+            assert!(registry.promoted_teams("2022-23").is_empty());
+            assert_eq!(registry.player_seasons(101668).len(), 1);
+
+            // Rice moved from West Ham to Arsenal
+            let moves = registry.transfers_into("2023-24");
+            assert_eq!(moves.len(), 1);
+            assert_eq!(
+                (moves[0].player_code, moves[0].from_team_code, moves[0].to_team_code),
+                (204480, 21, 3)
+            );
+            assert!(registry.club_changes(223094).is_empty());
+
+            // The current season links up with the archive through the club codes
+            let client = super::season_client();
+            let teams = client
+                .get_teams()
+                .await
+                .unwrap()
+                .iter()
+                .map(|team| fpl_conversions::convert_team(team).unwrap())
+                .collect::<Vec<_>>();
+            let players = fpl_conversions::convert_player_list(&client.get_players().await.unwrap())
+                .unwrap();
+            registry.add_season("2024-25", &teams, &players);
+
+            assert_eq!(registry.seasons(), ["2022-23", "2023-24", "2024-25"]);
+            assert_eq!(registry.same_team("2023-24", 1, "2024-25"), Some(1));
+            assert_eq!(registry.same_team("2023-24", 13, "2024-25"), Some(13));
+            assert!(registry.relegated_teams("2024-25").contains(&102));
+            let saka = players.get_player(&2).unwrap();
+            assert_eq!(registry.player_code("2024-25", 2), Some(saka.code));
+            assert_eq!(teams[saka.team_id as usize - 1].name, "Arsenal");
+        }
+
+        #[tokio::test]
+        async fn test_convert_game_settings() {
+            let bootstrap = super::season_client().get_bootstrap_static().await.unwrap();
+            let settings = fpl_conversions::convert_game_settings(&bootstrap).unwrap();
+
+            assert_eq!(settings.squad, fpl_game_settings::SquadRules::default());
+            assert_eq!(
+                fpl_conversions::convert_season_name(&bootstrap),
+                Some(String::from("2024-25"))
+            );
+            assert_eq!(settings.scoring, ScoringRules::for_season("2024-25").unwrap());
+            assert_eq!(settings.h2h_points.win, 3);
+            assert_eq!(
+                settings.phases_for(3).iter().map(|phase| phase.name.as_str()).collect::<Vec<_>>(),
+                vec!["Overall", "August"]
+            );
+            assert!(settings.chips_available_in(1).is_empty());
+            assert_eq!(settings.chips_available_in(2)[0].chip, fpl_managers::Chip::Wildcard);
+
+            // Arsenal's three players are too many under a limit of two
+            let players = fpl_conversions::convert_player_list(&bootstrap.elements).unwrap();
+            let squad = fpl_managers::FplManagerSquad {
+                manager_id: 1,
+                gameweek: 2,
+                active_chip: None,
+                picks: (1..=3)
+                    .map(|player_id| fpl_managers::FplSquadPick {
+                        player_id,
+                        position: player_id,
+                        multiplier: 1,
+                        is_captain: player_id == 2,
+                        is_vice_captain: player_id == 3,
+                    })
+                    .collect(),
+                automatic_subs: Vec::new(),
+                points: 0,
+                points_on_bench: 0,
+            };
+            let rules = fpl_game_settings::SquadRules {
+                max_per_team: 2,
+                ..settings.squad
+            };
+            assert_eq!(
+                rules.squad_problems(&squad, &players),
+                vec![
+                    String::from("The squad has 3 players rather than 15"),
+                    String::from("The squad has 3 players from team 1, the limit is 2"),
+                ]
+            );
+            assert_eq!(squad.starting_xi(&rules).len(), 3);
+        }
+
+        #[tokio::test]
+        async fn test_load_dataset() {
+            let mut dataset = crate::FplDataset::load(&super::season_client())
+                .await
+                .expect("Failed to load the dataset");
+
+            assert_eq!(dataset.teams.len(), 20);
+            assert_eq!(dataset.positions.len(), 4);
+            assert_eq!(dataset.fixtures.match_list.len(), 30);
+            assert_eq!(dataset.current_gameweek().unwrap().id, 2);
+
+            let arsenal = dataset.team_by_short_name("ARS").unwrap();
+            assert_eq!(dataset.team(arsenal.id).unwrap().name, "Arsenal");
+            let arsenal_players = dataset.players_in_team(arsenal.id);
+            assert_eq!(arsenal_players.len(), 3);
+            assert!(arsenal_players.windows(2).all(|pair| pair[0].id < pair[1].id));
+            assert_eq!(dataset.player(3).unwrap().name.display_name, "Gabriel");
+            assert_eq!(dataset.position(&fpl_positions::Position::FWD).unwrap().id, 4);
+            assert!(dataset
+                .players_in_position(&fpl_positions::Position::GK)
+                .iter()
+                .all(|player| player.position == fpl_positions::Position::GK));
+
+            assert_eq!(dataset.fixtures_in_gameweek(1).len(), 10);
+            assert_eq!(dataset.team_fixtures(arsenal.id).len(), 3);
+            assert_eq!(dataset.fixture(1).unwrap().home_team_id, 14);
+
+            let client = super::season_client();
+            let api_live = client.get_live_gameweek(1).await.unwrap();
+            let live = fpl_conversions::convert_live_gameweek(1, &api_live).unwrap();
+            let points = dataset.points_in_fixture(1, &live).unwrap().unwrap();
+            assert_eq!(points.len(), 6);
+            // Everyone plays once a gameweek, so the fixture is their whole gameweek. Without the
+            // live minutes and clean sheets merged in these totals would not line up.
+            for (player, points) in &points {
+                assert!(player.team_id == 14 || player.team_id == 9);
+                assert_eq!(Some(*points), live.official_points(player.id));
+            }
+            let breakdowns = dataset.points_breakdown_in_fixture(1, &live).unwrap().unwrap();
+            for breakdown in &breakdowns {
+                assert_eq!(Some(breakdown.total_points), live.official_points(breakdown.element));
+            }
+            assert!(dataset.points_in_fixture(0, &live).is_none());
+
+            let api_later = client.get_live_gameweek(2).await.unwrap();
+            let later = fpl_conversions::convert_live_gameweek(2, &api_later).unwrap();
+            assert!(matches!(
+                dataset.points_in_fixture(1, &later),
+                Some(Err(crate::FplDatasetError::Conversion(_)))
+            ));
+
+            dataset.fixtures.match_list[0].stats.add_statistic(9999, MatchStatistic::Minutes, 90);
+            assert!(matches!(
+                dataset.points_in_fixture(1, &live),
+                Some(Err(crate::FplDatasetError::Conversion(_)))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_load_dataset_with_unscheduled_fixture() {
+            let mut fixtures: Vec<serde_json::Value> = serde_json::from_str(include_str!(
+                "../../fpl_data/test_data/synthetic-2024-25/fixtures.json"
+            ))
+            .unwrap();
+            // A postponed fixture waits with no gameweek or kickoff, and the API may add stats
+            // the points engine does not know
+            fixtures.push(serde_json::json!({
+                "code": 2444999, "event": null, "finished": false, "finished_provisional": false,
+                "id": 999, "kickoff_time": null, "minutes": 0, "provisional_start_time": false,
+                "started": false, "team_a": 2, "team_a_score": null, "team_h": 1,
+                "team_h_score": null,
+                "stats": [{"identifier": "mng_underdog_win", "a": [], "h": [{"value": 1, "element": 3}]}]
+            }));
+            let transport = fpl_client::InMemoryTransport::new()
+                .with_json(
+                    "bootstrap-static/",
+                    include_str!("../../fpl_data/test_data/synthetic-2024-25/bootstrap-static.json"),
+                )
+                .with_json("fixtures/", &serde_json::Value::from(fixtures).to_string());
+
+            let dataset = crate::FplDataset::load(&fpl_client::FplClient::new().with_transport(transport))
+                .await
+                .expect("Failed to load the dataset");
+
+            let postponed = dataset.fixture(999).unwrap();
+            assert_eq!((postponed.event, postponed.kickoff_time), (None, None));
+            assert!(dataset.team_fixtures(1).iter().any(|fixture| fixture.id == 999));
+            assert_eq!(dataset.fixtures_in_gameweek(1).len(), 10);
+        }
+
         #[tokio::test]
         async fn test_get_fixtures() {
-            let api_fixtures = fpl_data::get_fixtures()
+            let api_fixtures = super::season_client()
+                .get_fixtures()
                 .await
                 .expect("Failed to get fixtures");
 
@@ -939,9 +4301,61 @@ mod tests {
                 })
                 .collect();
 
-            assert_eq!(fixtures.len(), 19 * 20);
+            assert_eq!(fixtures.len(), 30);
             assert!(fixtures[0].finished);
             assert_eq!(fixtures[0].home_team_id, 14);
         }
     }
+
+    // Run with `--features live-tests`; these need network access and follow the real season
+    #[cfg(feature = "live-tests")]
+    mod test_live {
+
+        use fpl_data::fpl_data;
+
+        use crate::{
+            fpl_conversions,
+            fpl_match_stats::{MatchStatisticMap, ScoringRules},
+        };
+
+        #[tokio::test]
+        async fn test_live_players() {
+            let api_players = fpl_data::get_players()
+                .await
+                .expect("Failed to get players");
+
+            let players = fpl_conversions::convert_player_list(&api_players).unwrap();
+
+            assert_eq!(players.player_list.len(), api_players.len());
+        }
+
+        #[tokio::test]
+        async fn test_live_teams() {
+            let api_teams = fpl_data::get_teams().await.expect("Failed to get teams");
+
+            for api_team in &api_teams {
+                fpl_conversions::convert_team(api_team).expect("Failed to convert team");
+            }
+            assert_eq!(api_teams.len(), 20);
+        }
+
+        #[tokio::test]
+        async fn test_live_fixtures() {
+            let api_players = fpl_data::get_players()
+                .await
+                .expect("Failed to get players");
+            let player_list = fpl_conversions::convert_player_list(&api_players).unwrap();
+            let api_fixtures = fpl_data::get_fixtures()
+                .await
+                .expect("Failed to get fixtures");
+
+            let match_list = fpl_conversions::convert_fixture_list(&api_fixtures).unwrap();
+            let match_stats: Vec<MatchStatisticMap> =
+                match_list.match_list.into_iter().map(|fixture| fixture.stats).collect();
+            for mut stats in match_stats {
+                stats.get_player_points_from_stats(&player_list, &ScoringRules::default()).unwrap();
+            }
+            assert_eq!(api_fixtures.len(), 19 * 20);
+        }
+    }
 }
@@ -1,95 +1,342 @@
 pub mod fpl_data {
-    use reqwest;
-    use serde::{Deserialize, Serialize};
+    use std::{collections::BTreeMap, fmt};
+
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+    use crate::fpl_client::FplClient;
+    use crate::fpl_schema::{
+        convert_nested_tolerant, convert_record_tolerant, convert_records_tolerant, DriftReport,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum FplDataError {
+        // The request never produced a response (connection refused, timeout, ...)
+        Transport { url: String, message: String },
+        // The API responded, but not with a success status
+        HttpStatus { url: String, status: u16 },
+        // The body was not json, or not the json shape we expected
+        JsonShape { context: String, message: String },
+        // A single record (player, team, fixture, ...) could not be deserialized
+        Record {
+            record_type: String,
+            id: Option<u64>,
+            field: Option<String>,
+            message: String,
+        },
+        MissingField { field: String },
+        // Reading or writing a local file (cache, recordings, ...) failed
+        Io { path: String, message: String },
+        // A csv file could be read but not parsed; line is where the bad row starts
+        Csv { path: String, line: Option<u64>, message: String },
+        // A client setting was out of range, e.g. a rate limit of no requests at all
+        Config { setting: String, message: String },
+        // The blocking client could not start the runtime it waits on
+        Runtime { message: String },
+        // The mock server could not listen on the address it was given
+        Server { address: String, message: String },
+    }
+
+    impl fmt::Display for FplDataError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FplDataError::Transport { url, message } => {
+                    write!(f, "Request to {} failed: {}", url, message)
+                }
+                FplDataError::HttpStatus { url, status } => {
+                    write!(f, "Request to {} failed with status {}", url, status)
+                }
+                FplDataError::JsonShape { context, message } => {
+                    write!(f, "Unexpected json in {}: {}", context, message)
+                }
+                FplDataError::Record {
+                    record_type,
+                    id,
+                    field,
+                    message,
+                } => {
+                    write!(f, "Failed to convert {}", record_type)?;
+                    if let Some(id) = id {
+                        write!(f, " {}", id)?;
+                    }
+                    if let Some(field) = field {
+                        write!(f, " (field {})", field)?;
+                    }
+                    write!(f, ": {}", message)
+                }
+                FplDataError::MissingField { field } => {
+                    write!(f, "Field {} not found in JSON object", field)
+                }
+                FplDataError::Io { path, message } => {
+                    write!(f, "Failed to access {}: {}", path, message)
+                }
+                FplDataError::Csv { path, line, message } => {
+                    write!(f, "Malformed csv in {}", path)?;
+                    if let Some(line) = line {
+                        write!(f, " at line {}", line)?;
+                    }
+                    write!(f, ": {}", message)
+                }
+                FplDataError::Config { setting, message } => {
+                    write!(f, "Invalid {}: {}", setting, message)
+                }
+                FplDataError::Runtime { message } => {
+                    write!(f, "Failed to start the runtime for the blocking client: {}", message)
+                }
+                FplDataError::Server { address, message } => {
+                    write!(f, "Failed to serve on {}: {}", address, message)
+                }
+            }
+        }
+    }
 
+    impl std::error::Error for FplDataError {}
 
     pub trait FplApiData {
 
         fn api_endpoint() -> String;
         fn json_field() -> String;
         type JsonExtractType;
-        fn from_json(json: serde_json::Value) -> Result<Self::JsonExtractType, String>;
+        // Differences between the json and the declared records are noted in `drift`
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self::JsonExtractType, FplDataError>;
+    }
+
+    // Endpoints that need a parameter, e.g. a player or manager id, and whose whole
+    // response is one document
+    pub trait FplApiQuery: Sized {
+
+        type Params;
+        fn api_endpoint(params: &Self::Params) -> String;
+        // Differences between the json and the declared records are noted in `drift`
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError>;
     }
 
-    fn convert_vec_to_generic<F, T>(values: Vec<serde_json::Value>, conversion_fn: F) -> T::JsonExtractType
+    // Convert each element of a json array, reporting the id and field of the first record
+    // that does not match the declared type rather than panicking on it.
+    pub fn convert_records<T>(record_type: &str, json: serde_json::Value) -> Result<Vec<T>, FplDataError>
     where
-        T: FplApiData,
-        T::JsonExtractType: FromIterator<T>,
-        F: Fn(serde_json::Value) -> T,
+        T: DeserializeOwned,
     {
-        values.into_iter().map(conversion_fn).collect()
+        if let serde_json::Value::Array(records) = json {
+            records
+                .into_iter()
+                .map(|record| convert_record(record_type, record))
+                .collect()
+        } else {
+            Err(FplDataError::JsonShape {
+                context: format!("{} list", record_type),
+                message: String::from("expected a json array"),
+            })
+        }
     }
 
-    async fn api_call(url: &str) -> Result<serde_json::Value, reqwest::Error> {
-        let response = reqwest::get(url).await?;
+    pub fn convert_record<T>(record_type: &str, record: serde_json::Value) -> Result<T, FplDataError>
+    where
+        T: DeserializeOwned,
+    {
+        let id = record.get("id").and_then(|id| id.as_u64());
 
-        // Return the reponse directly
-        response.json().await
+        serde_path_to_error::deserialize(record).map_err(|error| record_error(record_type, id, error))
     }
 
-    pub async fn get_component<T>() -> Result<T::JsonExtractType, String>
-    where
-        T: FplApiData
-    {
-        let endpoint = T::api_endpoint();
-        let data = api_call(&endpoint).await.expect("Failed to get data from API call");
+    pub(crate) fn record_error(
+        record_type: &str,
+        id: Option<u64>,
+        error: serde_path_to_error::Error<serde_json::Error>,
+    ) -> FplDataError {
+        let path = error.path().to_string();
+        let message = error.into_inner().to_string();
+
+        // A missing field is reported against the parent, so pull the name out of the message
+        let field = match message.strip_prefix("missing field `") {
+            Some(rest) => rest.split('`').next().map(String::from),
+            None if path != "." => Some(path),
+            None => None,
+        };
+
+        FplDataError::Record {
+            record_type: record_type.to_string(),
+            id,
+            field,
+            message,
+        }
+    }
+
+    // The API sends most decimals as strings ("4.5"), and sometimes an empty string for none
+    // at all. These parse them into f64 on the way in and write them back out as strings.
+    pub mod decimal_string {
+        use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawDecimal {
+            Number(f64),
+            Text(String),
+        }
+
+        fn parse<E: Error>(raw: RawDecimal) -> Result<Option<f64>, E> {
+            match raw {
+                RawDecimal::Number(value) => Ok(Some(value)),
+                RawDecimal::Text(text) if text.trim().is_empty() => Ok(None),
+                RawDecimal::Text(text) => match text.trim().parse::<f64>() {
+                    Ok(value) if value.is_finite() => Ok(Some(value)),
+                    _ => Err(E::custom(format!("invalid decimal string {:?}", text))),
+                },
+            }
+        }
 
-        match data {
-            serde_json::Value::Array(_) => T::from_json(data),
-            serde_json::Value::Object(mut object_data) => {
-                if let Some(field_value) = object_data.remove(&T::json_field()) {
-                    T::from_json(field_value)
+        pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        // An empty string is an error here; fields the API can leave blank use `optional`
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+            parse(RawDecimal::deserialize(deserializer)?)?
+                .ok_or_else(|| D::Error::custom("empty decimal string"))
+        }
+
+        // For fields the API can also leave null; null and the empty string are both None
+        pub mod optional {
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            use super::{parse, RawDecimal};
+
+            pub fn serialize<S: Serializer>(
+                value: &Option<f64>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => serializer.serialize_some(&value.to_string()),
+                    None => serializer.serialize_none(),
                 }
-                else {
-                    Err(format!("Field {} not found in JSON object", T::json_field()))
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<f64>, D::Error> {
+                match Option::<RawDecimal>::deserialize(deserializer)? {
+                    Some(raw) => parse(raw),
+                    None => Ok(None),
                 }
-            },
-            _ => Err(format!("Expected data to be a json object"))
+            }
         }
     }
 
-    pub async fn get_all_data() -> Result<serde_json::Value, reqwest::Error> {
-        let request_url = "https://fantasy.premierleague.com/api/bootstrap-static/";
-        api_call(&request_url).await
+    pub fn into_object(
+        json: serde_json::Value,
+        context: &str,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, FplDataError> {
+        match json {
+            serde_json::Value::Object(object_data) => Ok(object_data),
+            _ => Err(FplDataError::JsonShape {
+                context: context.to_string(),
+                message: String::from("expected a json object"),
+            }),
+        }
     }
 
-    pub async fn get_events() -> Result<Vec<FplApiGameweek>, String> {
-        get_component::<FplApiGameweek>().await
+    pub fn take_field(
+        object_data: &mut serde_json::Map<String, serde_json::Value>,
+        field: &str,
+    ) -> Result<serde_json::Value, FplDataError> {
+        object_data
+            .remove(field)
+            .ok_or_else(|| FplDataError::MissingField {
+                field: field.to_string(),
+            })
     }
 
-    pub async fn get_players() -> Result<Vec<FplApiPlayer>, String> {
-        get_component::<FplApiPlayer>().await
+    // The free functions below go through a default client pointed at the live API.
+    // Use an FplClient directly to change the base URL, timeouts or transport.
+
+    pub async fn get_component<T>() -> Result<T::JsonExtractType, FplDataError>
+    where
+        T: FplApiData
+    {
+        FplClient::new().get_component::<T>().await
     }
 
-    pub async fn get_positions() -> Result<Vec<FplApiPosition>, String> {
-        get_component::<FplApiPosition>().await
+    pub async fn get_all_data() -> Result<serde_json::Value, FplDataError> {
+        FplClient::new().get_all_data().await
     }
 
-    pub async fn get_player_count() -> Result<usize, &'static str> {
-        let all_data = get_all_data()
-            .await
-            .expect("Failed to get all data from API call");
+    pub async fn get_bootstrap_static() -> Result<BootstrapStatic, FplDataError> {
+        FplClient::new().get_bootstrap_static().await
+    }
 
-        if let serde_json::Value::Object(object_data) = all_data {
-            if let serde_json::Value::Number(player_count) = &object_data["total_players"] {
-                Ok(player_count.as_u64().unwrap() as usize)
-            } else {
-                Err("Expected total_players to be a number")
-            }
-        } else {
-            Err("Expected all data to be a json object")
-        }
+    pub async fn get_events() -> Result<Vec<FplApiGameweek>, FplDataError> {
+        FplClient::new().get_events().await
     }
 
-    pub async fn get_teams() -> Result<Vec<FplApiTeam>, String> {
-        get_component::<FplApiTeam>().await
+    pub async fn get_players() -> Result<Vec<FplApiPlayer>, FplDataError> {
+        FplClient::new().get_players().await
     }
 
-    pub async fn get_fixtures() -> Result<Vec<FplApiFixture>, String> {
-        get_component::<FplApiFixture>().await
+    pub async fn get_positions() -> Result<Vec<FplApiPosition>, FplDataError> {
+        FplClient::new().get_positions().await
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    pub async fn get_player_count() -> Result<usize, FplDataError> {
+        FplClient::new().get_player_count().await
+    }
+
+    pub async fn get_teams() -> Result<Vec<FplApiTeam>, FplDataError> {
+        FplClient::new().get_teams().await
+    }
+
+    pub async fn get_fixtures() -> Result<Vec<FplApiFixture>, FplDataError> {
+        FplClient::new().get_fixtures().await
+    }
+
+    pub async fn get_element_summary(player_id: u32) -> Result<FplApiElementSummary, FplDataError> {
+        FplClient::new().get_element_summary(player_id).await
+    }
+
+    pub async fn get_live_gameweek(gameweek: u32) -> Result<FplApiLiveGameweek, FplDataError> {
+        FplClient::new().get_live_gameweek(gameweek).await
+    }
+
+    pub async fn get_entry(entry_id: u32) -> Result<FplApiEntry, FplDataError> {
+        FplClient::new().get_entry(entry_id).await
+    }
+
+    pub async fn get_entry_history(entry_id: u32) -> Result<FplApiEntryHistory, FplDataError> {
+        FplClient::new().get_entry_history(entry_id).await
+    }
+
+    pub async fn get_entry_picks(entry_id: u32, gameweek: u32) -> Result<FplApiEntryPicks, FplDataError> {
+        FplClient::new().get_entry_picks(entry_id, gameweek).await
+    }
+
+    pub async fn get_entry_transfers(entry_id: u32) -> Result<Vec<FplApiTransfer>, FplDataError> {
+        FplClient::new().get_entry_transfers(entry_id).await
+    }
+
+    pub async fn get_classic_league(
+        league_id: u32,
+    ) -> Result<FplApiLeagueStandings<FplApiClassicStanding>, FplDataError> {
+        FplClient::new().get_classic_league(league_id).await
+    }
+
+    pub async fn get_h2h_league(
+        league_id: u32,
+    ) -> Result<FplApiLeagueStandings<FplApiH2hStanding>, FplDataError> {
+        FplClient::new().get_h2h_league(league_id).await
+    }
+
+    pub async fn get_h2h_matches(league_id: u32) -> Result<Vec<FplApiH2hMatch>, FplDataError> {
+        FplClient::new().get_h2h_matches(league_id).await
+    }
+
+    // Every response is parsed tolerantly, with the differences from these structs noted in a
+    // `DriftReport`. The bootstrap and fixture records also keep whatever fields they do not
+    // declare in `unknown_fields`, so new data is not lost before the structs catch up.
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiTeam {
         pub id: u64,
         pub code: u64,
@@ -109,37 +356,30 @@ pub mod fpl_data {
         pub strength_attack_away: u32,
         pub strength_defence_home: u32,
         pub strength_defence_away: u32,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
     }
 
     impl FplApiData for FplApiTeam {
         fn api_endpoint() -> String {
-            format!("https://fantasy.premierleague.com/api/bootstrap-static/")
+            format!("bootstrap-static/")
         }
 
         fn json_field() -> String {
             format!("teams")
         }
 
-        fn from_json(json: serde_json::Value) -> Result<Self::JsonExtractType, String> {
-
-            // The team list is in "teams"
-            if let serde_json::Value::Array(teams_list) = json {
-                let team_conversion = |json_value: serde_json::Value| -> FplApiTeam {
-                    serde_json::from_value(json_value).expect("Failed to convert team")
-                };
-
-                Ok(teams_list.into_iter().map(team_conversion).collect())
-
-            } else {
-                Err(format!("Expected teams to be a json array"))
-            }
-
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self::JsonExtractType, FplDataError> {
+            convert_records_tolerant("team", json, drift)
         }
 
         type JsonExtractType = Vec<FplApiTeam>;
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiPlayer {
         pub chance_of_playing_next_round: Option<u32>,
         pub chance_of_playing_this_round: Option<u32>,
@@ -150,20 +390,25 @@ pub mod fpl_data {
         pub cost_change_start_fall: i32,
         pub dreamteam_count: u32,
         pub element_type: u32,
-        pub ep_next: Option<String>,
-        pub ep_this: Option<String>,
+        #[serde(with = "decimal_string::optional")]
+        pub ep_next: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub ep_this: Option<f64>,
         pub event_points: i32,
         pub first_name: String,
-        pub form: String,
+        #[serde(with = "decimal_string::optional")]
+        pub form: Option<f64>,
         pub id: u32,
         pub in_dreamteam: bool,
         pub news: String,
         pub news_added: Option<String>,
         pub now_cost: u32,
         pub photo: String,
-        pub points_per_game: String,
+        #[serde(with = "decimal_string::optional")]
+        pub points_per_game: Option<f64>,
         pub second_name: String,
-        pub selected_by_percent: String,
+        #[serde(with = "decimal_string::optional")]
+        pub selected_by_percent: Option<f64>,
         pub special: bool,
         pub squad_number: Option<u32>,
         pub status: String,
@@ -174,8 +419,10 @@ pub mod fpl_data {
         pub transfers_in_event: u32,
         pub transfers_out: u32,
         pub transfers_out_event: u32,
-        pub value_form: String,
-        pub value_season: String,
+        #[serde(with = "decimal_string::optional")]
+        pub value_form: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub value_season: Option<f64>,
         pub web_name: String,
         pub minutes: u32,
         pub goals_scored: u32,
@@ -190,15 +437,23 @@ pub mod fpl_data {
         pub saves: u32,
         pub bonus: u32,
         pub bps: i32,
-        pub influence: String,
-        pub creativity: String,
-        pub threat: String,
-        pub ict_index: String,
+        #[serde(with = "decimal_string::optional")]
+        pub influence: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub creativity: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub threat: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub ict_index: Option<f64>,
         pub starts: u32,
-        pub expected_goals: String,
-        pub expected_assists: String,
-        pub expected_goal_involvements: String,
-        pub expected_goals_conceded: String,
+        #[serde(with = "decimal_string::optional")]
+        pub expected_goals: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub expected_assists: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub expected_goal_involvements: Option<f64>,
+        #[serde(with = "decimal_string::optional")]
+        pub expected_goals_conceded: Option<f64>,
         pub influence_rank: u32,
         pub influence_rank_type: u32,
         pub creativity_rank: u32,
@@ -229,34 +484,30 @@ pub mod fpl_data {
         pub selected_rank_type: u32,
         pub starts_per_90: f64,
         pub clean_sheets_per_90: f64,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
     }
 
     impl FplApiData for FplApiPlayer {
         fn api_endpoint() -> String {
-            format!("https://fantasy.premierleague.com/api/bootstrap-static/")
+            format!("bootstrap-static/")
         }
 
         fn json_field() -> String {
             format!("elements")
         }
 
-        fn from_json(json: serde_json::Value) -> Result<Vec<FplApiPlayer>, String> {
-            
-            if let serde_json::Value::Array(players_list) = json {
-                let player_conversion = |json_value: serde_json::Value| -> FplApiPlayer {
-                    serde_json::from_value(json_value).expect("Failed to convert player")
-                };
-                Ok(convert_vec_to_generic(players_list, player_conversion))
-            } else {
-                Err(format!("Expected players to be a json array"))
-            }
-
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Vec<FplApiPlayer>, FplDataError> {
+            convert_records_tolerant("player", json, drift)
         }
 
         type JsonExtractType = Vec<FplApiPlayer>;
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiPosition {
         pub id: u32,
         pub plural_name: String,
@@ -269,56 +520,50 @@ pub mod fpl_data {
         pub ui_shirt_specific: bool,
         pub sub_positions_locked: Vec<u32>,
         pub element_count: u32,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
     }
 
     impl FplApiData for FplApiPosition {
         fn api_endpoint() -> String {
-            format!("https://fantasy.premierleague.com/api/bootstrap-static/")
+            format!("bootstrap-static/")
         }
 
         fn json_field() -> String {
             format!("element_types")
         }
 
-        fn from_json(json: serde_json::Value) -> Result<Vec<FplApiPosition>, String> {
-        
-            // The team list is in "teams"
-            if let serde_json::Value::Array(position_list) = json {
-                let position_conversion = |json_value: serde_json::Value| -> FplApiPosition {
-                    serde_json::from_value(json_value).expect("Failed to convert team")
-                };
-
-                Ok(position_list.into_iter().map(position_conversion).collect())
-
-            } else {
-                Err(format!("Expected positions to be a json array"))
-            }
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Vec<FplApiPosition>, FplDataError> {
+            convert_records_tolerant("position", json, drift)
         }
 
         type JsonExtractType = Vec<FplApiPosition>;
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct FplApiFixturePlayerStat {
         pub value: i32,
         pub element: u32, // Player (element) id
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct FplApiFixtureStats {
         pub identifier: String,
         pub a: Vec<FplApiFixturePlayerStat>, // Away team
         pub h: Vec<FplApiFixturePlayerStat>, // Home team
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct FplApiFixture {
         pub code: u32,
-        pub event: u32,
+        pub event: Option<u32>,
         pub finished: bool,
         pub finished_provisional: bool,
         pub id: u32,
-        pub kickoff_time: String,
+        pub kickoff_time: Option<String>,
         pub minutes: u32,
         pub provisional_start_time: bool,
         pub started: bool,
@@ -327,25 +572,20 @@ pub mod fpl_data {
         pub team_h: u64,
         pub team_h_score: Option<u32>,
         pub stats: Vec<FplApiFixtureStats>,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
     }
 
     impl FplApiData for FplApiFixture {
         fn api_endpoint() -> String {
-            format!("https://fantasy.premierleague.com/api/fixtures/")
+            format!("fixtures/")
         }
 
-        fn from_json(json: serde_json::Value) -> Result<Self::JsonExtractType, String> {
-            if let serde_json::Value::Array(fixtures) = json {
-
-                let fixture_conversion = |json_value: serde_json::Value| -> FplApiFixture {
-                    serde_json::from_value(json_value).expect("Failed to convert fixtures")
-                };
-
-                Ok(fixtures.into_iter().map(fixture_conversion).collect())
-
-            } else {
-                Err(format!("Expected fixtures to be a json array"))
-            }
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self::JsonExtractType, FplDataError> {
+            convert_records_tolerant("fixture", json, drift)
         }
 
         fn json_field() -> String {
@@ -355,84 +595,4173 @@ pub mod fpl_data {
         type JsonExtractType = Vec<Self>;
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiChipPlay {
-        chip_name: String,
-        num_played: u32,
+        pub chip_name: String,
+        pub num_played: u32,
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiTopElementInfo {
-        id: Option<u32>,
-        points: Option<u32>,
+        pub id: Option<u32>,
+        pub points: Option<u32>,
     }
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Default)]
     pub struct FplApiGameweek {
-        id: u32,
-        name: String,
-        deadline_time: String,
-        release_time: Option<String>,
-        average_entry_score: u32,
-        finished: bool,
-        data_checked: bool,
-        highest_scoring_entry: Option<u32>,
-        deadline_time_epoch: u64,
-        deadline_time_game_offset: u32,
-        highest_score: Option<u32>,
-        is_previous: bool,
-        is_current: bool,
-        is_next: bool,
-        cup_leagues_created: bool,
-        h2h_ko_matches_created: bool,
-        ranked_count: u64,
-        chip_plays: Vec<FplApiChipPlay>,
-        most_selected: Option<u32>,
-        most_transferred_in: Option<u32>,
-        top_element: Option<u32>,
-        top_element_info: Option<FplApiTopElementInfo>,
-        transfers_made: u64,
-        most_captained: Option<u32>,
-        most_vice_captained: Option<u32>,
+        pub id: u32,
+        pub name: String,
+        pub deadline_time: String,
+        pub release_time: Option<String>,
+        pub average_entry_score: u32,
+        pub finished: bool,
+        pub data_checked: bool,
+        pub highest_scoring_entry: Option<u32>,
+        pub deadline_time_epoch: u64,
+        pub deadline_time_game_offset: u32,
+        pub highest_score: Option<u32>,
+        pub is_previous: bool,
+        pub is_current: bool,
+        pub is_next: bool,
+        pub cup_leagues_created: bool,
+        pub h2h_ko_matches_created: bool,
+        pub ranked_count: u64,
+        pub chip_plays: Vec<FplApiChipPlay>,
+        pub most_selected: Option<u32>,
+        pub most_transferred_in: Option<u32>,
+        pub top_element: Option<u32>,
+        pub top_element_info: Option<FplApiTopElementInfo>,
+        pub transfers_made: u64,
+        pub most_captained: Option<u32>,
+        pub most_vice_captained: Option<u32>,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
     }
 
     impl FplApiData for FplApiGameweek {
 
         fn api_endpoint() -> String {
-            format!("https://fantasy.premierleague.com/api/bootstrap-static/")
+            format!("bootstrap-static/")
         }
 
         fn json_field() -> String {
             format!("events")
         }
 
-        fn from_json(json: serde_json::Value) -> Result<Self::JsonExtractType, String> {
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self::JsonExtractType, FplDataError> {
+            convert_records_tolerant("gameweek", json, drift)
+        }
 
-            // The team list is in "events"
-            if let serde_json::Value::Array(gameweek_list) = json {
-                let event_conversion = |json_value: serde_json::Value| -> FplApiGameweek {
-                    serde_json::from_value(json_value).expect("Failed to convert gameweek")
-                };
+        type JsonExtractType = Vec<FplApiGameweek>;
+    }
 
-                Ok(gameweek_list.into_iter().map(event_conversion).collect())
+    // A scoring value from the game settings, either the same for every position or keyed by
+    // the position's short name ("GKP", "DEF", "MID", "FWD")
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(untagged)]
+    pub enum FplApiScoringValue {
+        All(i32),
+        ByPosition(BTreeMap<String, i32>),
+    }
 
-            } else {
-                Err(format!("Expected events to be a json array"))
-            }
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiGameSettings {
+        pub squad_squadplay: u32, // Players in the starting line up
+        pub squad_squadsize: u32,
+        pub squad_team_limit: u32, // Most players allowed from one club
+        pub squad_total_spend: u32, // Tenths of a million
+        pub transfers_cap: u32,
+        pub transfers_sell_on_fee: f64,
+        pub max_extra_free_transfers: u32,
+        pub league_points_h2h_win: u32,
+        pub league_points_h2h_draw: u32,
+        pub league_points_h2h_lose: u32,
+        pub league_h2h_tiebreak_stats: Vec<String>,
+        pub stats_form_days: u32,
+        pub timezone: String,
+        // Points per statistic, keyed like the fixture stats identifiers. Not every version of
+        // the document carries these.
+        pub scoring: BTreeMap<String, FplApiScoringValue>,
+        #[serde(flatten)]
+        pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+    }
+
+    // A stretch of gameweeks with its own league table, e.g. the overall season or a month
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPhase {
+        pub id: u32,
+        pub name: String,
+        pub start_event: u32,
+        pub stop_event: u32,
+        pub highest_score: Option<u32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiChipOverrides {
+        pub rules: serde_json::Map<String, serde_json::Value>,
+        pub scoring: BTreeMap<String, FplApiScoringValue>,
+        pub element_types: Vec<serde_json::Value>,
+        pub pick_multiplier: Option<u32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiChip {
+        pub id: u32,
+        pub name: String, // e.g. "wildcard" or "3xc"
+        pub number: u32, // How many times it can be played in the window
+        pub start_event: u32,
+        pub stop_event: u32,
+        pub chip_type: String, // "transfer" or "team"
+        pub overrides: FplApiChipOverrides,
+    }
+
+    // The whole bootstrap-static document, downloaded and parsed in a single request. The
+    // records are parsed tolerantly, with any differences from the declared types noted in
+    // `drift`.
+    #[derive(Serialize, Deserialize, Debug)]
+    pub struct BootstrapStatic {
+        pub events: Vec<FplApiGameweek>,
+        #[serde(default)]
+        pub game_settings: FplApiGameSettings,
+        #[serde(default)]
+        pub phases: Vec<FplApiPhase>,
+        pub teams: Vec<FplApiTeam>,
+        pub total_players: u64,
+        pub elements: Vec<FplApiPlayer>,
+        pub element_types: Vec<FplApiPosition>,
+        #[serde(default)]
+        pub chips: Vec<FplApiChip>,
+        #[serde(skip)]
+        pub drift: DriftReport,
+    }
 
+    impl BootstrapStatic {
+        pub fn api_endpoint() -> String {
+            String::from("bootstrap-static/")
         }
 
-        type JsonExtractType = Vec<FplApiGameweek>;
+        pub fn from_json(json: serde_json::Value) -> Result<BootstrapStatic, FplDataError> {
+            let mut object_data = into_object(json, "bootstrap-static")?;
+            let mut take_field = |field: &str| take_field(&mut object_data, field);
+
+            let mut drift = DriftReport::default();
+
+            let events = convert_records_tolerant("gameweek", take_field("events")?, &mut drift)?;
+            let teams = convert_records_tolerant("team", take_field("teams")?, &mut drift)?;
+            let elements = convert_records_tolerant("player", take_field("elements")?, &mut drift)?;
+            let element_types =
+                convert_records_tolerant("position", take_field("element_types")?, &mut drift)?;
+            let total_players = take_field("total_players")?.as_u64().ok_or_else(|| {
+                FplDataError::JsonShape {
+                    context: String::from("total_players"),
+                    message: String::from("expected a number"),
+                }
+            })?;
+
+            let game_settings = take_field("game_settings")
+                .map(|settings| convert_record_tolerant("game settings", settings, &mut drift))
+                .unwrap_or_else(|_| Ok(FplApiGameSettings::default()))?;
+            let phases = take_field("phases")
+                .map(|phases| convert_records_tolerant("phase", phases, &mut drift))
+                .unwrap_or_else(|_| Ok(Vec::new()))?;
+            let chips = take_field("chips")
+                .map(|chips| convert_records_tolerant("chip", chips, &mut drift))
+                .unwrap_or_else(|_| Ok(Vec::new()))?;
+
+            Ok(BootstrapStatic {
+                events,
+                game_settings,
+                phases,
+                teams,
+                total_players,
+                elements,
+                element_types,
+                chips,
+                drift,
+            })
+        }
+
+        pub fn events(&self) -> &[FplApiGameweek] {
+            &self.events
+        }
+
+        pub fn players(&self) -> &[FplApiPlayer] {
+            &self.elements
+        }
+
+        pub fn positions(&self) -> &[FplApiPosition] {
+            &self.element_types
+        }
+
+        pub fn teams(&self) -> &[FplApiTeam] {
+            &self.teams
+        }
+
+        pub fn player_count(&self) -> usize {
+            self.total_players as usize
+        }
+
+        pub fn get_player(&self, player_id: u32) -> Option<&FplApiPlayer> {
+            self.elements.iter().find(|player| player.id == player_id)
+        }
+
+        pub fn get_team(&self, team_id: u64) -> Option<&FplApiTeam> {
+            self.teams.iter().find(|team| team.id == team_id)
+        }
     }
 
-}
+    // A fixture still to be played, as listed in a player's element-summary
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPlayerFixture {
+        pub id: u32,
+        pub code: u32,
+        pub team_h: u64,
+        pub team_h_score: Option<u32>,
+        pub team_a: u64,
+        pub team_a_score: Option<u32>,
+        pub event: Option<u32>,
+        pub finished: bool,
+        pub minutes: u32,
+        pub provisional_start_time: bool,
+        pub kickoff_time: Option<String>,
+        pub event_name: Option<String>,
+        pub is_home: bool,
+        pub difficulty: u32,
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::fpl_data;
+    // One fixture the player has taken part in this season
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPlayerHistory {
+        pub element: u32,
+        pub fixture: u32,
+        pub opponent_team: u64,
+        pub total_points: i32,
+        pub was_home: bool,
+        pub kickoff_time: String,
+        pub team_h_score: Option<u32>,
+        pub team_a_score: Option<u32>,
+        pub round: u32,
+        #[serde(default)]
+        pub modified: bool,
+        pub minutes: u32,
+        pub goals_scored: u32,
+        pub assists: u32,
+        pub clean_sheets: u32,
+        pub goals_conceded: u32,
+        pub own_goals: u32,
+        pub penalties_saved: u32,
+        pub penalties_missed: u32,
+        pub yellow_cards: u32,
+        pub red_cards: u32,
+        pub saves: u32,
+        pub bonus: u32,
+        pub bps: i32,
+        #[serde(with = "decimal_string")]
+        pub influence: f64,
+        #[serde(with = "decimal_string")]
+        pub creativity: f64,
+        #[serde(with = "decimal_string")]
+        pub threat: f64,
+        #[serde(with = "decimal_string")]
+        pub ict_index: f64,
+        pub starts: u32,
+        #[serde(with = "decimal_string")]
+        pub expected_goals: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_assists: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goal_involvements: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goals_conceded: f64,
+        pub value: u32,
+        pub transfers_balance: i64,
+        pub selected: u64,
+        pub transfers_in: u64,
+        pub transfers_out: u64,
+    }
 
-    #[tokio::test]
-    async fn test_get_data() {
-        let _ = fpl_data::get_all_data().await;
+    // Season totals for a previous season, keyed by the player's code rather than id
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPlayerHistoryPast {
+        pub season_name: String,
+        pub element_code: u32,
+        pub start_cost: u32,
+        pub end_cost: u32,
+        pub total_points: i32,
+        pub minutes: u32,
+        pub goals_scored: u32,
+        pub assists: u32,
+        pub clean_sheets: u32,
+        pub goals_conceded: u32,
+        pub own_goals: u32,
+        pub penalties_saved: u32,
+        pub penalties_missed: u32,
+        pub yellow_cards: u32,
+        pub red_cards: u32,
+        pub saves: u32,
+        pub bonus: u32,
+        pub bps: i32,
+        #[serde(with = "decimal_string")]
+        pub influence: f64,
+        #[serde(with = "decimal_string")]
+        pub creativity: f64,
+        #[serde(with = "decimal_string")]
+        pub threat: f64,
+        #[serde(with = "decimal_string")]
+        pub ict_index: f64,
+        pub starts: u32,
+        #[serde(with = "decimal_string")]
+        pub expected_goals: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_assists: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goal_involvements: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goals_conceded: f64,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiElementSummary {
+        pub fixtures: Vec<FplApiPlayerFixture>,
+        pub history: Vec<FplApiPlayerHistory>,
+        pub history_past: Vec<FplApiPlayerHistoryPast>,
+    }
+
+    impl FplApiQuery for FplApiElementSummary {
+        type Params = u32; // Player (element) id
+
+        fn api_endpoint(player_id: &u32) -> String {
+            format!("element-summary/{}/", player_id)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut object_data = into_object(json, "element-summary")?;
+            let mut take_field = |field: &str| take_field(&mut object_data, field);
+
+            Ok(FplApiElementSummary {
+                fixtures: convert_records_tolerant(
+                    "player fixture",
+                    take_field("fixtures")?,
+                    drift,
+                )?,
+                history: convert_records_tolerant("player history", take_field("history")?, drift)?,
+                history_past: convert_records_tolerant(
+                    "player past season",
+                    take_field("history_past")?,
+                    drift,
+                )?,
+            })
+        }
+    }
+
+    // Running totals for one player across every fixture they have in the gameweek
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLiveStats {
+        pub minutes: u32,
+        pub goals_scored: u32,
+        pub assists: u32,
+        pub clean_sheets: u32,
+        pub goals_conceded: u32,
+        pub own_goals: u32,
+        pub penalties_saved: u32,
+        pub penalties_missed: u32,
+        pub yellow_cards: u32,
+        pub red_cards: u32,
+        pub saves: u32,
+        pub bonus: u32,
+        pub bps: i32,
+        // Clearances, blocks, interceptions and tackles (plus recoveries outside defence), only
+        // recorded from 2025/26
+        #[serde(default)]
+        pub defensive_contribution: u32,
+        #[serde(with = "decimal_string")]
+        pub influence: f64,
+        #[serde(with = "decimal_string")]
+        pub creativity: f64,
+        #[serde(with = "decimal_string")]
+        pub threat: f64,
+        #[serde(with = "decimal_string")]
+        pub ict_index: f64,
+        pub starts: u32,
+        #[serde(with = "decimal_string")]
+        pub expected_goals: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_assists: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goal_involvements: f64,
+        #[serde(with = "decimal_string")]
+        pub expected_goals_conceded: f64,
+        pub total_points: i32,
+        pub in_dreamteam: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLiveExplainStat {
+        pub identifier: String,
+        pub points: i32,
+        pub value: i32,
+        #[serde(default)]
+        pub points_modification: i32,
+    }
+
+    // How the player's points break down in a single fixture
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLiveExplain {
+        pub fixture: u32,
+        pub stats: Vec<FplApiLiveExplainStat>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLiveElement {
+        pub id: u32,
+        pub stats: FplApiLiveStats,
+        pub explain: Vec<FplApiLiveExplain>,
+        #[serde(default)]
+        pub modified: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLiveGameweek {
+        pub elements: Vec<FplApiLiveElement>,
+    }
+
+    impl FplApiQuery for FplApiLiveGameweek {
+        type Params = u32; // Gameweek (event) id
+
+        fn api_endpoint(gameweek: &u32) -> String {
+            format!("event/{}/live/", gameweek)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut object_data = into_object(json, "event live")?;
+            let mut elements = take_field(&mut object_data, "elements")?;
+
+            // The nested stats are settled first, innermost out
+            let records = elements.as_array_mut().into_iter().flatten();
+            for element in records.filter_map(|element| element.as_object_mut()) {
+                convert_nested_tolerant::<FplApiLiveStats>("live stats", element, "stats", drift)?;
+                let explain = element.get_mut("explain").and_then(|explain| explain.as_array_mut());
+                let fixtures = explain.into_iter().flatten();
+                for fixture in fixtures.filter_map(|fixture| fixture.as_object_mut()) {
+                    convert_nested_tolerant::<FplApiLiveExplainStat>(
+                        "live explain stat",
+                        fixture,
+                        "stats",
+                        drift,
+                    )?;
+                }
+                convert_nested_tolerant::<FplApiLiveExplain>(
+                    "live explain",
+                    element,
+                    "explain",
+                    drift,
+                )?;
+            }
+
+            Ok(FplApiLiveGameweek {
+                elements: convert_records_tolerant("live element", elements, drift)?,
+            })
+        }
+    }
+
+    // A league as listed against a manager's entry
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryLeague {
+        pub id: u32,
+        pub name: String,
+        pub short_name: Option<String>,
+        pub created: String,
+        pub closed: bool,
+        pub league_type: String, // "x" for private leagues, "s" for system leagues
+        pub scoring: String,     // "c" classic, "h" head-to-head
+        pub admin_entry: Option<u32>,
+        pub start_event: u32,
+        pub entry_rank: Option<u32>,
+        pub entry_last_rank: Option<u32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryLeagues {
+        pub classic: Vec<FplApiEntryLeague>,
+        pub h2h: Vec<FplApiEntryLeague>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntry {
+        pub id: u32,
+        pub joined_time: String,
+        pub started_event: u32,
+        pub favourite_team: Option<u64>,
+        pub player_first_name: String,
+        pub player_last_name: String,
+        pub player_region_id: u32,
+        pub player_region_name: String,
+        pub player_region_iso_code_short: String,
+        pub player_region_iso_code_long: String,
+        pub summary_overall_points: Option<i32>,
+        pub summary_overall_rank: Option<u32>,
+        pub summary_event_points: Option<i32>,
+        pub summary_event_rank: Option<u32>,
+        pub current_event: Option<u32>,
+        pub leagues: FplApiEntryLeagues,
+        pub name: String,
+        pub name_change_blocked: bool,
+        pub kit: Option<String>,
+        pub last_deadline_bank: Option<i32>,
+        pub last_deadline_value: Option<i32>,
+        pub last_deadline_total_transfers: Option<u32>,
+    }
+
+    impl FplApiQuery for FplApiEntry {
+        type Params = u32; // Entry (manager) id
+
+        fn api_endpoint(entry_id: &u32) -> String {
+            format!("entry/{}/", entry_id)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut entry = into_object(json, "entry")?;
+            let leagues = entry.get_mut("leagues").and_then(|leagues| leagues.as_object_mut());
+            if let Some(leagues) = leagues {
+                for scoring in ["classic", "h2h"] {
+                    convert_nested_tolerant::<FplApiEntryLeague>(
+                        "entry league",
+                        leagues,
+                        scoring,
+                        drift,
+                    )?;
+                }
+            }
+            convert_nested_tolerant::<FplApiEntryLeagues>(
+                "entry leagues",
+                &mut entry,
+                "leagues",
+                drift,
+            )?;
+
+            convert_record_tolerant("entry", serde_json::Value::Object(entry), drift)
+        }
+    }
+
+    // A manager's score and squad value for one gameweek; bank and value are in tenths of a million
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryEvent {
+        pub event: u32,
+        pub points: i32,
+        pub total_points: i32,
+        pub rank: Option<u32>,
+        pub rank_sort: Option<u32>,
+        pub overall_rank: Option<u32>,
+        pub bank: i32,
+        pub value: i32,
+        pub event_transfers: u32,
+        pub event_transfers_cost: i32,
+        pub points_on_bench: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntrySeason {
+        pub season_name: String,
+        pub total_points: i32,
+        pub rank: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryChip {
+        pub name: String,
+        pub time: String,
+        pub event: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryHistory {
+        pub current: Vec<FplApiEntryEvent>,
+        pub past: Vec<FplApiEntrySeason>,
+        pub chips: Vec<FplApiEntryChip>,
+    }
+
+    impl FplApiQuery for FplApiEntryHistory {
+        type Params = u32; // Entry (manager) id
+
+        fn api_endpoint(entry_id: &u32) -> String {
+            format!("entry/{}/history/", entry_id)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut object_data = into_object(json, "entry history")?;
+            let mut take_field = |field: &str| take_field(&mut object_data, field);
+
+            Ok(FplApiEntryHistory {
+                current: convert_records_tolerant("entry event", take_field("current")?, drift)?,
+                past: convert_records_tolerant("entry season", take_field("past")?, drift)?,
+                chips: convert_records_tolerant("entry chip", take_field("chips")?, drift)?,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPick {
+        pub element: u32,
+        pub position: u32, // Squad slot, 1-11 start and 12-15 are the bench in order
+        pub multiplier: u32,
+        pub is_captain: bool,
+        pub is_vice_captain: bool,
+        #[serde(default)]
+        pub element_type: Option<u32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiAutomaticSub {
+        pub entry: u32,
+        pub element_in: u32,
+        pub element_out: u32,
+        pub event: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiEntryPicks {
+        pub active_chip: Option<String>,
+        pub automatic_subs: Vec<FplApiAutomaticSub>,
+        pub entry_history: FplApiEntryEvent,
+        pub picks: Vec<FplApiPick>,
+    }
+
+    impl FplApiQuery for FplApiEntryPicks {
+        type Params = (u32, u32); // Entry (manager) id and gameweek
+
+        fn api_endpoint((entry_id, gameweek): &(u32, u32)) -> String {
+            format!("entry/{}/event/{}/picks/", entry_id, gameweek)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut picks = into_object(json, "entry picks")?;
+            convert_nested_tolerant::<FplApiAutomaticSub>(
+                "automatic sub",
+                &mut picks,
+                "automatic_subs",
+                drift,
+            )?;
+            convert_nested_tolerant::<FplApiEntryEvent>(
+                "entry event",
+                &mut picks,
+                "entry_history",
+                drift,
+            )?;
+            convert_nested_tolerant::<FplApiPick>("pick", &mut picks, "picks", drift)?;
+
+            convert_record_tolerant("entry picks", serde_json::Value::Object(picks), drift)
+        }
+    }
+
+    // Transfer costs are in tenths of a million
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiTransfer {
+        pub element_in: u32,
+        pub element_in_cost: u32,
+        pub element_out: u32,
+        pub element_out_cost: u32,
+        pub entry: u32,
+        pub event: u32,
+        pub time: String,
+    }
+
+    impl FplApiQuery for Vec<FplApiTransfer> {
+        type Params = u32; // Entry (manager) id
+
+        fn api_endpoint(entry_id: &u32) -> String {
+            format!("entry/{}/transfers/", entry_id)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            convert_records_tolerant("transfer", json, drift)
+        }
+    }
+
+    // One page of a paginated listing; the league endpoints are walked until has_next is false
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiPage<T> {
+        pub has_next: bool,
+        pub page: u32,
+        pub results: Vec<T>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLeagueInfo {
+        pub id: u32,
+        pub name: String,
+        pub created: String,
+        pub closed: bool,
+        pub max_entries: Option<u32>,
+        pub league_type: String,
+        pub scoring: String,
+        pub admin_entry: Option<u32>,
+        pub start_event: u32,
+        pub code_privacy: Option<String>,
+        #[serde(default)]
+        pub has_cup: bool,
+        pub rank: Option<u32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiClassicStanding {
+        pub id: u32,
+        pub event_total: i32,
+        pub player_name: String,
+        pub rank: u32,
+        pub last_rank: u32,
+        pub rank_sort: u32,
+        pub total: i32,
+        pub entry: u32,
+        pub entry_name: String,
+    }
+
+    // H2H totals are league points (3 for a win, 1 for a draw), points_for is the FPL score
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiH2hStanding {
+        pub id: u32,
+        pub division: u32,
+        pub entry: u32,
+        pub player_name: String,
+        pub rank: u32,
+        pub last_rank: u32,
+        pub rank_sort: u32,
+        pub total: i32,
+        pub entry_name: String,
+        pub matches_played: u32,
+        pub matches_won: u32,
+        pub matches_drawn: u32,
+        pub matches_lost: u32,
+        pub points_for: i32,
+    }
+
+    // entry_1 and entry_2 are empty when the match is a bye
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiH2hMatch {
+        pub id: u32,
+        pub entry_1_entry: Option<u32>,
+        pub entry_1_name: String,
+        pub entry_1_player_name: String,
+        pub entry_1_points: i32,
+        pub entry_1_win: u32,
+        pub entry_1_draw: u32,
+        pub entry_1_loss: u32,
+        pub entry_1_total: i32,
+        pub entry_2_entry: Option<u32>,
+        pub entry_2_name: String,
+        pub entry_2_player_name: String,
+        pub entry_2_points: i32,
+        pub entry_2_win: u32,
+        pub entry_2_draw: u32,
+        pub entry_2_loss: u32,
+        pub entry_2_total: i32,
+        pub is_knockout: bool,
+        pub league: u32,
+        pub winner: Option<u32>,
+        pub event: u32,
+        pub is_bye: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct FplApiLeagueStandingsPage<T> {
+        pub league: FplApiLeagueInfo,
+        pub standings: FplApiPage<T>,
+    }
+
+    impl FplApiQuery for FplApiLeagueStandingsPage<FplApiClassicStanding> {
+        type Params = (u32, u32); // League id and page, starting from 1
+
+        fn api_endpoint((league_id, page): &(u32, u32)) -> String {
+            format!("leagues-classic/{}/standings/?page_standings={}", league_id, page)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            convert_standings_page("classic league standings", "classic standing", json, drift)
+        }
+    }
+
+    impl FplApiQuery for FplApiLeagueStandingsPage<FplApiH2hStanding> {
+        type Params = (u32, u32); // League id and page, starting from 1
+
+        fn api_endpoint((league_id, page): &(u32, u32)) -> String {
+            format!("leagues-h2h/{}/standings/?page_standings={}", league_id, page)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            convert_standings_page("h2h league standings", "h2h standing", json, drift)
+        }
+    }
+
+    impl FplApiQuery for FplApiPage<FplApiH2hMatch> {
+        type Params = (u32, u32); // League id and page, starting from 1
+
+        fn api_endpoint((league_id, page): &(u32, u32)) -> String {
+            format!("leagues-h2h-matches/league/{}/?page={}", league_id, page)
+        }
+
+        fn from_json(
+            json: serde_json::Value,
+            drift: &mut DriftReport,
+        ) -> Result<Self, FplDataError> {
+            let mut page = into_object(json, "h2h matches")?;
+            convert_nested_tolerant::<FplApiH2hMatch>("h2h match", &mut page, "results", drift)?;
+
+            convert_record_tolerant("h2h matches", serde_json::Value::Object(page), drift)
+        }
+    }
+
+    fn convert_standings_page<T>(
+        record_type: &str,
+        standing_type: &str,
+        json: serde_json::Value,
+        drift: &mut DriftReport,
+    ) -> Result<FplApiLeagueStandingsPage<T>, FplDataError>
+    where
+        T: DeserializeOwned + Serialize + Default,
+    {
+        let mut page = into_object(json, record_type)?;
+        convert_nested_tolerant::<FplApiLeagueInfo>("league", &mut page, "league", drift)?;
+        let standings = page.get_mut("standings").and_then(|standings| standings.as_object_mut());
+        if let Some(standings) = standings {
+            convert_nested_tolerant::<T>(standing_type, standings, "results", drift)?;
+        }
+        convert_nested_tolerant::<FplApiPage<T>>("standings page", &mut page, "standings", drift)?;
+
+        convert_record_tolerant(record_type, serde_json::Value::Object(page), drift)
+    }
+
+    // Every page of a league's standings, joined together
+    #[derive(Serialize, Deserialize, Debug)]
+    pub struct FplApiLeagueStandings<T> {
+        pub league: FplApiLeagueInfo,
+        pub standings: Vec<T>,
+    }
+
+}
+
+pub mod fpl_client {
+    use std::{
+        collections::HashMap,
+        path::Path,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use tokio::sync::Semaphore;
+
+    use crate::fpl_cache::FplCache;
+    use crate::fpl_recording::{FplRecorder, ReplayTransport};
+    use crate::fpl_throttle::{RateLimit, RateLimiter, RetryPolicy};
+    use crate::fpl_schema::DriftReport;
+    use crate::fpl_data::{
+        BootstrapStatic, FplApiClassicStanding, FplApiData, FplApiElementSummary, FplApiEntry,
+        FplApiEntryHistory, FplApiEntryPicks, FplApiFixture, FplApiGameweek, FplApiH2hMatch,
+        FplApiH2hStanding, FplApiLeagueStandings, FplApiLeagueStandingsPage, FplApiLiveGameweek,
+        FplApiPage, FplApiPlayer, FplApiPosition, FplApiQuery, FplApiTeam, FplApiTransfer,
+        FplDataError,
+    };
+
+    pub const DEFAULT_BASE_URL: &str = "https://fantasy.premierleague.com/api/";
+    pub const DEFAULT_USER_AGENT: &str = concat!("fpl_data/", env!("CARGO_PKG_VERSION"));
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    #[derive(Debug, Clone)]
+    pub struct FplRequest {
+        pub url: String,
+        pub endpoint: String, // Path relative to the client's base URL
+        pub headers: Vec<(String, String)>,
+        pub timeout: Duration,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FplResponse {
+        pub status: u16,
+        pub headers: HashMap<String, String>, // Header names are lower case
+        pub body: String,
+    }
+
+    impl FplResponse {
+        pub fn new(status: u16, body: &str) -> Self {
+            FplResponse {
+                status,
+                headers: HashMap::new(),
+                body: body.to_string(),
+            }
+        }
+
+        pub fn with_header(mut self, name: &str, value: &str) -> Self {
+            self.headers.insert(name.to_lowercase(), value.to_string());
+            self
+        }
+
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.get(&name.to_lowercase()).map(String::as_str)
+        }
+
+        pub fn is_success(&self) -> bool {
+            (200..300).contains(&self.status)
+        }
+    }
+
+    // Anything that can turn a request into a response: the real HTTP client, a local
+    // stand-in server behind a different base URL, or canned responses held in memory.
+    #[async_trait]
+    pub trait FplTransport: Send + Sync {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError>;
+    }
+
+    // Lets a transport be shared, e.g. kept by a test to inspect after handing it to a client
+    #[async_trait]
+    impl<T> FplTransport for Arc<T>
+    where
+        T: FplTransport + ?Sized,
+    {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            (**self).send(request).await
+        }
+    }
+
+    pub struct ReqwestTransport {
+        client: reqwest::Client,
+    }
+
+    impl ReqwestTransport {
+        pub fn new() -> Self {
+            ReqwestTransport {
+                client: reqwest::Client::new(),
+            }
+        }
+
+        pub fn with_client(client: reqwest::Client) -> Self {
+            ReqwestTransport { client }
+        }
+    }
+
+    impl Default for ReqwestTransport {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[async_trait]
+    impl FplTransport for ReqwestTransport {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            let mut builder = self.client.get(&request.url).timeout(request.timeout);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+
+            let transport_error = |error: reqwest::Error| FplDataError::Transport {
+                url: request.url.clone(),
+                message: error.to_string(),
+            };
+
+            let response = builder.send().await.map_err(transport_error)?;
+
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.as_str().to_lowercase(), value.to_string()))
+                })
+                .collect();
+            let body = response.text().await.map_err(transport_error)?;
+
+            Ok(FplResponse {
+                status,
+                headers,
+                body,
+            })
+        }
+    }
+
+    // Serves canned responses keyed by endpoint, e.g. "bootstrap-static/".
+    // Unknown endpoints get a 404 so callers see the same failure as against the real API.
+    #[derive(Default)]
+    pub struct InMemoryTransport {
+        responses: Mutex<HashMap<String, FplResponse>>,
+    }
+
+    impl InMemoryTransport {
+        pub fn new() -> Self {
+            InMemoryTransport {
+                responses: Mutex::new(HashMap::new()),
+            }
+        }
+
+        pub fn with_json(self, endpoint: &str, body: &str) -> Self {
+            self.add_response(endpoint, FplResponse::new(200, body));
+            self
+        }
+
+        pub fn add_response(&self, endpoint: &str, response: FplResponse) {
+            self.responses
+                .lock()
+                .unwrap()
+                .insert(endpoint.to_string(), response);
+        }
+    }
+
+    #[async_trait]
+    impl FplTransport for InMemoryTransport {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            let responses = self.responses.lock().unwrap();
+            Ok(responses
+                .get(&request.endpoint)
+                .cloned()
+                .unwrap_or_else(|| FplResponse::new(404, "The game is being updated.")))
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct FplClient {
+        base_url: String,
+        timeout: Duration,
+        user_agent: String,
+        transport: Arc<dyn FplTransport>,
+        cache: Option<FplCache>,
+        recorder: Option<Arc<FplRecorder>>,
+        // Shared between clones, so a fan-out over cloned clients is limited as a whole
+        rate_limiter: Option<Arc<RateLimiter>>,
+        concurrency: Option<(usize, Arc<Semaphore>)>,
+        retry_policy: RetryPolicy,
+        // Schema drift seen in every response parsed so far, also shared between clones
+        drift: Arc<Mutex<DriftReport>>,
+    }
+
+    impl FplClient {
+        pub fn new() -> Self {
+            FplClient {
+                base_url: DEFAULT_BASE_URL.to_string(),
+                timeout: DEFAULT_TIMEOUT,
+                user_agent: DEFAULT_USER_AGENT.to_string(),
+                transport: Arc::new(ReqwestTransport::new()),
+                cache: None,
+                recorder: None,
+                rate_limiter: None,
+                concurrency: None,
+                retry_policy: RetryPolicy::default(),
+                drift: Arc::new(Mutex::new(DriftReport::default())),
+            }
+        }
+
+        // A client that answers only from a directory recorded with an FplRecorder. A replay
+        // gives the same answer however often it is asked, so there is nothing to retry.
+        pub fn replay<P: AsRef<Path>>(directory: P) -> Result<Self, FplDataError> {
+            Ok(FplClient::new()
+                .with_transport(ReplayTransport::from_directory(directory)?)
+                .with_retry_policy(RetryPolicy::none()))
+        }
+
+        pub fn with_base_url(mut self, base_url: &str) -> Self {
+            // Endpoints are joined straight onto the base URL so it must end in a slash
+            self.base_url = if base_url.ends_with('/') {
+                base_url.to_string()
+            } else {
+                format!("{}/", base_url)
+            };
+            self
+        }
+
+        pub fn with_timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+            self.user_agent = user_agent.to_string();
+            self
+        }
+
+        pub fn with_transport<T>(mut self, transport: T) -> Self
+        where
+            T: FplTransport + 'static,
+        {
+            self.transport = Arc::new(transport);
+            self
+        }
+
+        pub fn with_cache(mut self, cache: FplCache) -> Self {
+            self.cache = Some(cache);
+            self
+        }
+
+        pub fn with_recorder(mut self, recorder: FplRecorder) -> Self {
+            self.recorder = Some(Arc::new(recorder));
+            self
+        }
+
+        pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+            self.rate_limiter = Some(Arc::new(RateLimiter::new(rate_limit)));
+            self
+        }
+
+        // A limit of zero would never let a request through, so it is refused
+        pub fn with_max_concurrency(
+            mut self,
+            max_concurrency: usize,
+        ) -> Result<Self, FplDataError> {
+            if max_concurrency == 0 {
+                return Err(FplDataError::Config {
+                    setting: String::from("max concurrency"),
+                    message: String::from("expected at least one request at a time, got 0"),
+                });
+            }
+            self.concurrency = Some((max_concurrency, Arc::new(Semaphore::new(max_concurrency))));
+            Ok(self)
+        }
+
+        pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+            self.retry_policy = retry_policy;
+            self
+        }
+
+        pub fn base_url(&self) -> &str {
+            &self.base_url
+        }
+
+        pub fn timeout(&self) -> Duration {
+            self.timeout
+        }
+
+        pub fn user_agent(&self) -> &str {
+            &self.user_agent
+        }
+
+        pub fn cache(&self) -> Option<&FplCache> {
+            self.cache.as_ref()
+        }
+
+        pub fn recorder(&self) -> Option<&FplRecorder> {
+            self.recorder.as_deref()
+        }
+
+        pub fn rate_limit(&self) -> Option<RateLimit> {
+            self.rate_limiter.as_ref().map(|limiter| limiter.limit())
+        }
+
+        pub fn max_concurrency(&self) -> Option<usize> {
+            self.concurrency.as_ref().map(|(max_concurrency, _)| *max_concurrency)
+        }
+
+        pub fn retry_policy(&self) -> &RetryPolicy {
+            &self.retry_policy
+        }
+
+        // How the records parsed so far differed from their declared types, across every
+        // endpoint
+        pub fn drift(&self) -> DriftReport {
+            self.drift.lock().unwrap().clone()
+        }
+
+        pub fn clear_drift(&self) {
+            *self.drift.lock().unwrap() = DriftReport::default();
+        }
+
+        fn note_drift(&self, drift: &DriftReport) {
+            self.drift.lock().unwrap().merge(drift);
+        }
+
+        pub fn endpoint_url(&self, endpoint: &str) -> String {
+            format!("{}{}", self.base_url, endpoint.trim_start_matches('/'))
+        }
+
+        fn request(&self, endpoint: &str) -> FplRequest {
+            FplRequest {
+                url: self.endpoint_url(endpoint),
+                endpoint: endpoint.to_string(),
+                headers: vec![("user-agent".to_string(), self.user_agent.clone())],
+                timeout: self.timeout,
+            }
+        }
+
+        // Every request the client makes goes through here, so this is where responses are recorded
+        pub async fn send(&self, endpoint: &str) -> Result<FplResponse, FplDataError> {
+            let endpoint = endpoint.trim_start_matches('/');
+            let response = self.fetch(endpoint).await?;
+
+            if let Some(recorder) = &self.recorder {
+                recorder.record(endpoint, &response).await?;
+            }
+
+            Ok(response)
+        }
+
+        async fn fetch(&self, endpoint: &str) -> Result<FplResponse, FplDataError> {
+            let cache = match &self.cache {
+                Some(cache) => cache,
+                None => return self.send_to_transport(self.request(endpoint)).await,
+            };
+
+            // Entries are keyed by the full URL, so clients of different servers can share a cache
+            let mut request = self.request(endpoint);
+            let cached = cache.load(&request.url).await;
+            if let Some(entry) = &cached {
+                if cache.is_fresh(entry) {
+                    return Ok(entry.to_response());
+                }
+            }
+
+            // Stale or missing: ask the server, revalidating whatever we already hold
+            if let Some(entry) = &cached {
+                request.headers.extend(entry.validation_headers());
+            }
+
+            let response = self.send_to_transport(request.clone()).await?;
+
+            match cached {
+                Some(entry) if response.status == 304 => {
+                    let entry = cache.refresh(entry, &response).await?;
+                    Ok(entry.to_response())
+                }
+                _ if response.is_success() => {
+                    cache.store(&request, &response).await?;
+                    Ok(response)
+                }
+                _ => Ok(response),
+            }
+        }
+
+        // Throttling and retries only apply to requests that reach the transport, never cache hits
+        async fn send_to_transport(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            let mut attempt = 0;
+            loop {
+                let result = {
+                    // Held for the request itself but not while backing off
+                    let _permit = match &self.concurrency {
+                        Some((_, semaphore)) => semaphore.acquire().await.ok(),
+                        None => None,
+                    };
+                    if let Some(rate_limiter) = &self.rate_limiter {
+                        rate_limiter.acquire().await;
+                    }
+                    self.transport.send(request.clone()).await
+                };
+
+                if attempt >= self.retry_policy.max_retries || !RetryPolicy::is_retryable(&result) {
+                    return result;
+                }
+                tokio::time::sleep(self.retry_policy.delay(attempt, result.as_ref().ok())).await;
+                attempt += 1;
+            }
+        }
+
+        pub async fn api_call(&self, endpoint: &str) -> Result<serde_json::Value, FplDataError> {
+            let response = self.send(endpoint).await?;
+
+            if !response.is_success() {
+                return Err(FplDataError::HttpStatus {
+                    url: self.endpoint_url(endpoint),
+                    status: response.status,
+                });
+            }
+
+            serde_json::from_str(&response.body).map_err(|e| FplDataError::JsonShape {
+                context: self.endpoint_url(endpoint),
+                message: e.to_string(),
+            })
+        }
+
+        pub async fn get_component<T>(&self) -> Result<T::JsonExtractType, FplDataError>
+        where
+            T: FplApiData,
+        {
+            let data = self.api_call(&T::api_endpoint()).await?;
+            let mut drift = DriftReport::default();
+
+            let result = match data {
+                serde_json::Value::Array(_) => T::from_json(data, &mut drift),
+                serde_json::Value::Object(mut object_data) => {
+                    if let Some(field_value) = object_data.remove(&T::json_field()) {
+                        T::from_json(field_value, &mut drift)
+                    } else {
+                        Err(FplDataError::MissingField {
+                            field: T::json_field(),
+                        })
+                    }
+                }
+                _ => Err(FplDataError::JsonShape {
+                    context: self.endpoint_url(&T::api_endpoint()),
+                    message: String::from("expected a json object or array"),
+                }),
+            };
+
+            self.note_drift(&drift);
+            result
+        }
+
+        pub async fn get_all_data(&self) -> Result<serde_json::Value, FplDataError> {
+            self.api_call(&BootstrapStatic::api_endpoint()).await
+        }
+
+        pub async fn get_bootstrap_static(&self) -> Result<BootstrapStatic, FplDataError> {
+            let bootstrap = BootstrapStatic::from_json(self.get_all_data().await?)?;
+            self.note_drift(&bootstrap.drift);
+            Ok(bootstrap)
+        }
+
+        // The bootstrap components are views over one snapshot. Callers that need more
+        // than one of them should fetch the snapshot once with get_bootstrap_static.
+
+        pub async fn get_events(&self) -> Result<Vec<FplApiGameweek>, FplDataError> {
+            Ok(self.get_bootstrap_static().await?.events)
+        }
+
+        pub async fn get_players(&self) -> Result<Vec<FplApiPlayer>, FplDataError> {
+            Ok(self.get_bootstrap_static().await?.elements)
+        }
+
+        pub async fn get_positions(&self) -> Result<Vec<FplApiPosition>, FplDataError> {
+            Ok(self.get_bootstrap_static().await?.element_types)
+        }
+
+        pub async fn get_player_count(&self) -> Result<usize, FplDataError> {
+            Ok(self.get_bootstrap_static().await?.player_count())
+        }
+
+        pub async fn get_teams(&self) -> Result<Vec<FplApiTeam>, FplDataError> {
+            Ok(self.get_bootstrap_static().await?.teams)
+        }
+
+        pub async fn get_fixtures(&self) -> Result<Vec<FplApiFixture>, FplDataError> {
+            self.get_component::<FplApiFixture>().await
+        }
+
+        pub async fn get_query<T>(&self, params: &T::Params) -> Result<T, FplDataError>
+        where
+            T: FplApiQuery,
+        {
+            let data = self.api_call(&T::api_endpoint(params)).await?;
+            let mut drift = DriftReport::default();
+            let result = T::from_json(data, &mut drift);
+
+            self.note_drift(&drift);
+            result
+        }
+
+        pub async fn get_element_summary(
+            &self,
+            player_id: u32,
+        ) -> Result<FplApiElementSummary, FplDataError> {
+            self.get_query::<FplApiElementSummary>(&player_id).await
+        }
+
+        pub async fn get_live_gameweek(
+            &self,
+            gameweek: u32,
+        ) -> Result<FplApiLiveGameweek, FplDataError> {
+            self.get_query::<FplApiLiveGameweek>(&gameweek).await
+        }
+
+        pub async fn get_entry(&self, entry_id: u32) -> Result<FplApiEntry, FplDataError> {
+            self.get_query::<FplApiEntry>(&entry_id).await
+        }
+
+        pub async fn get_entry_history(
+            &self,
+            entry_id: u32,
+        ) -> Result<FplApiEntryHistory, FplDataError> {
+            self.get_query::<FplApiEntryHistory>(&entry_id).await
+        }
+
+        pub async fn get_entry_picks(
+            &self,
+            entry_id: u32,
+            gameweek: u32,
+        ) -> Result<FplApiEntryPicks, FplDataError> {
+            self.get_query::<FplApiEntryPicks>(&(entry_id, gameweek)).await
+        }
+
+        pub async fn get_entry_transfers(
+            &self,
+            entry_id: u32,
+        ) -> Result<Vec<FplApiTransfer>, FplDataError> {
+            self.get_query::<Vec<FplApiTransfer>>(&entry_id).await
+        }
+
+        pub async fn get_classic_league(
+            &self,
+            league_id: u32,
+        ) -> Result<FplApiLeagueStandings<FplApiClassicStanding>, FplDataError> {
+            self.get_league_standings(league_id).await
+        }
+
+        pub async fn get_h2h_league(
+            &self,
+            league_id: u32,
+        ) -> Result<FplApiLeagueStandings<FplApiH2hStanding>, FplDataError> {
+            self.get_league_standings(league_id).await
+        }
+
+        pub async fn get_h2h_matches(
+            &self,
+            league_id: u32,
+        ) -> Result<Vec<FplApiH2hMatch>, FplDataError> {
+            let mut matches = Vec::new();
+            let mut page = 1;
+            loop {
+                let api_page = self
+                    .get_query::<FplApiPage<FplApiH2hMatch>>(&(league_id, page))
+                    .await?;
+                let last_page = !api_page.has_next || api_page.results.is_empty();
+                matches.extend(api_page.results);
+                if last_page {
+                    return Ok(matches);
+                }
+                page += 1;
+            }
+        }
+
+        async fn get_league_standings<T>(
+            &self,
+            league_id: u32,
+        ) -> Result<FplApiLeagueStandings<T>, FplDataError>
+        where
+            FplApiLeagueStandingsPage<T>: FplApiQuery<Params = (u32, u32)>,
+        {
+            let mut standings = Vec::new();
+            let mut page = 1;
+            loop {
+                let api_page = self
+                    .get_query::<FplApiLeagueStandingsPage<T>>(&(league_id, page))
+                    .await?;
+                // An empty page would otherwise keep us asking for the next one forever
+                let last_page = !api_page.standings.has_next || api_page.standings.results.is_empty();
+                standings.extend(api_page.standings.results);
+                if last_page {
+                    return Ok(FplApiLeagueStandings {
+                        league: api_page.league,
+                        standings,
+                    });
+                }
+                page += 1;
+            }
+        }
+    }
+
+    impl Default for FplClient {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+pub mod fpl_cache {
+    use std::{
+        path::{Path, PathBuf},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::fpl_client::{FplRequest, FplResponse};
+    use crate::fpl_data::FplDataError;
+
+    // How long a cached response is used without asking the server again.
+    // Once it expires the entry is revalidated with its ETag/Last-Modified validators.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CacheTtl {
+        pub bootstrap_static: Duration,
+        pub fixtures: Duration,
+        pub live: Duration,
+        pub default: Duration,
+    }
+
+    impl CacheTtl {
+        pub fn for_endpoint(&self, endpoint: &str) -> Duration {
+            let endpoint = endpoint.trim_start_matches('/');
+            if endpoint.starts_with("bootstrap-static") {
+                self.bootstrap_static
+            } else if endpoint.starts_with("fixtures") {
+                self.fixtures
+            } else if endpoint.starts_with("event/") && endpoint.contains("/live") {
+                self.live
+            } else {
+                self.default
+            }
+        }
+    }
+
+    impl Default for CacheTtl {
+        fn default() -> Self {
+            CacheTtl {
+                bootstrap_static: Duration::from_secs(60 * 60),
+                fixtures: Duration::from_secs(15 * 60),
+                live: Duration::from_secs(60),
+                default: Duration::from_secs(5 * 60),
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct CachedResponse {
+        pub url: String,
+        pub endpoint: String,
+        pub fetched_at: u64, // Seconds since the unix epoch
+        pub etag: Option<String>,
+        pub last_modified: Option<String>,
+        pub body: String,
+    }
+
+    impl CachedResponse {
+        pub fn age(&self) -> Duration {
+            Duration::from_secs(unix_time().saturating_sub(self.fetched_at))
+        }
+
+        pub fn validation_headers(&self) -> Vec<(String, String)> {
+            let mut headers = Vec::new();
+            if let Some(etag) = &self.etag {
+                headers.push((String::from("if-none-match"), etag.clone()));
+            }
+            if let Some(last_modified) = &self.last_modified {
+                headers.push((String::from("if-modified-since"), last_modified.clone()));
+            }
+            headers
+        }
+
+        pub fn to_response(&self) -> FplResponse {
+            let mut response = FplResponse::new(200, &self.body);
+            if let Some(etag) = &self.etag {
+                response = response.with_header("etag", etag);
+            }
+            if let Some(last_modified) = &self.last_modified {
+                response = response.with_header("last-modified", last_modified);
+            }
+            response
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct FplCache {
+        directory: PathBuf,
+        ttl: CacheTtl,
+    }
+
+    impl FplCache {
+        pub fn new<P: AsRef<Path>>(directory: P) -> Self {
+            FplCache {
+                directory: directory.as_ref().to_path_buf(),
+                ttl: CacheTtl::default(),
+            }
+        }
+
+        pub fn with_ttl(mut self, ttl: CacheTtl) -> Self {
+            self.ttl = ttl;
+            self
+        }
+
+        pub fn directory(&self) -> &Path {
+            &self.directory
+        }
+
+        pub fn ttl(&self) -> &CacheTtl {
+            &self.ttl
+        }
+
+        pub fn path(&self, url: &str) -> PathBuf {
+            self.directory.join(cache_file_name(url))
+        }
+
+        pub fn is_fresh(&self, entry: &CachedResponse) -> bool {
+            entry.age() < self.ttl.for_endpoint(&entry.endpoint)
+        }
+
+        // A missing or unreadable entry is just a cache miss
+        pub async fn load(&self, url: &str) -> Option<CachedResponse> {
+            let contents = tokio::fs::read_to_string(self.path(url)).await.ok()?;
+            serde_json::from_str(&contents).ok()
+        }
+
+        pub async fn store(
+            &self,
+            request: &FplRequest,
+            response: &FplResponse,
+        ) -> Result<CachedResponse, FplDataError> {
+            let entry = CachedResponse {
+                url: request.url.clone(),
+                endpoint: request.endpoint.clone(),
+                fetched_at: unix_time(),
+                etag: response.header("etag").map(String::from),
+                last_modified: response.header("last-modified").map(String::from),
+                body: response.body.clone(),
+            };
+            self.write(&entry).await?;
+            Ok(entry)
+        }
+
+        // The server confirmed the entry is unchanged (304), so restart its TTL. A 304 may still
+        // carry new validators, which replace the stored ones.
+        pub async fn refresh(
+            &self,
+            mut entry: CachedResponse,
+            response: &FplResponse,
+        ) -> Result<CachedResponse, FplDataError> {
+            entry.fetched_at = unix_time();
+            if let Some(etag) = response.header("etag") {
+                entry.etag = Some(etag.to_string());
+            }
+            if let Some(last_modified) = response.header("last-modified") {
+                entry.last_modified = Some(last_modified.to_string());
+            }
+            self.write(&entry).await?;
+            Ok(entry)
+        }
+
+        pub async fn clear(&self) -> Result<(), FplDataError> {
+            match tokio::fs::remove_dir_all(&self.directory).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(&self.directory, e)),
+                _ => Ok(()),
+            }
+        }
+
+        async fn write(&self, entry: &CachedResponse) -> Result<(), FplDataError> {
+            let path = self.path(&entry.url);
+            tokio::fs::create_dir_all(&self.directory)
+                .await
+                .map_err(|e| io_error(&self.directory, e))?;
+
+            let contents = serde_json::to_string(entry).map_err(|e| io_error(&path, e))?;
+            tokio::fs::write(&path, contents)
+                .await
+                .map_err(|e| io_error(&path, e))
+        }
+    }
+
+    // "event/5/live/" -> "event_5_live.json", "leagues-classic/1/standings/?page_standings=2"
+    // -> "leagues-classic_1_standings_page_standings_2.json"
+    pub fn endpoint_file_name(endpoint: &str) -> String {
+        let name: String = endpoint
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+            .collect();
+        let name: Vec<&str> = name.split('_').filter(|part| !part.is_empty()).collect();
+        format!("{}.json", name.join("_"))
+    }
+
+    // The cache file for a full request URL. Every byte other than an ascii letter, digit, '-'
+    // or '.' is escaped as '_' and two hex digits, so two URLs never share a file:
+    // "https://example.com/api/fixtures/" -> "https_3A_2F_2Fexample.com_2Fapi_2Ffixtures_2F.json"
+    pub fn cache_file_name(url: &str) -> String {
+        let mut name = String::with_capacity(url.len() + 5);
+        for byte in url.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'.' {
+                name.push(byte as char);
+            } else {
+                name.push_str(&format!("_{:02X}", byte));
+            }
+        }
+        name.push_str(".json");
+        name
+    }
+
+    pub(crate) fn io_error<E: std::fmt::Display>(path: &Path, error: E) -> FplDataError {
+        FplDataError::Io {
+            path: path.display().to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    fn unix_time() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+pub mod fpl_recording {
+    use std::{
+        collections::{BTreeMap, HashMap, VecDeque},
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Mutex,
+        },
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+
+    use crate::fpl_cache::{endpoint_file_name, io_error};
+    use crate::fpl_client::{FplRequest, FplResponse, FplTransport};
+    use crate::fpl_data::FplDataError;
+
+    // One response as the client received it. The body is kept as the exact text from
+    // the server so a replayed session parses identically.
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct RecordedResponse {
+        pub sequence: u64,
+        pub recorded_at: u64, // Milliseconds since the unix epoch
+        pub endpoint: String,
+        pub status: u16,
+        pub headers: BTreeMap<String, String>,
+        pub body: String,
+    }
+
+    impl RecordedResponse {
+        pub fn to_response(&self) -> FplResponse {
+            FplResponse {
+                status: self.status,
+                headers: self.headers.clone().into_iter().collect(),
+                body: self.body.clone(),
+            }
+        }
+    }
+
+    // Writes each response to "<sequence>_<recorded_at>_<endpoint>.json" in the directory.
+    // Recording into a directory that already holds a session carries on its sequence.
+    pub struct FplRecorder {
+        directory: PathBuf,
+        sequence: AtomicU64,
+    }
+
+    impl FplRecorder {
+        pub fn new<P: AsRef<Path>>(directory: P) -> Result<Self, FplDataError> {
+            let directory = directory.as_ref().to_path_buf();
+            std::fs::create_dir_all(&directory).map_err(|e| io_error(&directory, e))?;
+
+            let next_sequence = load_recordings(&directory)?
+                .last()
+                .map(|recording| recording.sequence + 1)
+                .unwrap_or_default();
+
+            Ok(FplRecorder {
+                directory,
+                sequence: AtomicU64::new(next_sequence),
+            })
+        }
+
+        pub fn directory(&self) -> &Path {
+            &self.directory
+        }
+
+        pub async fn record(
+            &self,
+            endpoint: &str,
+            response: &FplResponse,
+        ) -> Result<PathBuf, FplDataError> {
+            let recording = RecordedResponse {
+                sequence: self.sequence.fetch_add(1, Ordering::SeqCst),
+                recorded_at: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|time| time.as_millis() as u64)
+                    .unwrap_or_default(),
+                endpoint: endpoint.to_string(),
+                status: response.status,
+                headers: response.headers.clone().into_iter().collect(),
+                body: response.body.clone(),
+            };
+
+            let path = self.directory.join(format!(
+                "{:06}_{}_{}",
+                recording.sequence,
+                recording.recorded_at,
+                endpoint_file_name(endpoint)
+            ));
+            let contents =
+                serde_json::to_string_pretty(&recording).map_err(|e| io_error(&path, e))?;
+            tokio::fs::write(&path, contents)
+                .await
+                .map_err(|e| io_error(&path, e))?;
+
+            Ok(path)
+        }
+    }
+
+    // All recordings in a directory, in the order they were made
+    pub fn load_recordings<P: AsRef<Path>>(directory: P) -> Result<Vec<RecordedResponse>, FplDataError> {
+        let directory = directory.as_ref();
+        let entries = std::fs::read_dir(directory).map_err(|e| io_error(directory, e))?;
+
+        let mut recordings = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| io_error(directory, e))?.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+                continue;
+            }
+
+            let contents = std::fs::read_to_string(&path).map_err(|e| io_error(&path, e))?;
+            let recording: RecordedResponse = serde_json::from_str(&contents).map_err(|e| {
+                FplDataError::JsonShape {
+                    context: path.display().to_string(),
+                    message: e.to_string(),
+                }
+            })?;
+            recordings.push(recording);
+        }
+
+        recordings.sort_by_key(|recording| recording.sequence);
+        Ok(recordings)
+    }
+
+    // Plays a recorded session back without touching the network. Repeated requests for an
+    // endpoint get its recordings in order, and the last one once they run out.
+    pub struct ReplayTransport {
+        responses: Mutex<HashMap<String, VecDeque<FplResponse>>>,
+    }
+
+    impl ReplayTransport {
+        pub fn from_directory<P: AsRef<Path>>(directory: P) -> Result<Self, FplDataError> {
+            Ok(Self::from_recordings(load_recordings(directory)?))
+        }
+
+        pub fn from_recordings(recordings: Vec<RecordedResponse>) -> Self {
+            let mut responses: HashMap<String, VecDeque<FplResponse>> = HashMap::new();
+            for recording in recordings {
+                responses
+                    .entry(recording.endpoint.clone())
+                    .or_default()
+                    .push_back(recording.to_response());
+            }
+
+            ReplayTransport {
+                responses: Mutex::new(responses),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl FplTransport for ReplayTransport {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            let mut responses = self.responses.lock().unwrap();
+            let queue = responses
+                .get_mut(&request.endpoint)
+                .ok_or_else(|| FplDataError::Transport {
+                    url: request.url.clone(),
+                    message: String::from("No recorded response for this endpoint"),
+                })?;
+
+            if queue.len() > 1 {
+                Ok(queue.pop_front().unwrap())
+            } else {
+                Ok(queue.front().cloned().unwrap())
+            }
+        }
+    }
+}
+
+pub mod fpl_throttle {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+        time::Duration,
+    };
+
+    use tokio::{sync::Mutex, time::Instant};
+
+    use crate::fpl_client::FplResponse;
+    use crate::fpl_data::FplDataError;
+
+    // Token bucket: up to `burst` requests go straight away, then they are let through
+    // at `requests_per_second` as the bucket refills. The fields are only set through
+    // `per_second` and `with_burst`, so a limit always lets some requests through.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct RateLimit {
+        requests_per_second: f64,
+        burst: u32,
+    }
+
+    impl RateLimit {
+        pub fn per_second(requests_per_second: f64) -> Result<Self, FplDataError> {
+            if !(requests_per_second.is_finite() && requests_per_second > 0.0) {
+                return Err(FplDataError::Config {
+                    setting: String::from("rate limit"),
+                    message: format!(
+                        "expected a positive number of requests per second, got {}",
+                        requests_per_second
+                    ),
+                });
+            }
+            Ok(RateLimit {
+                requests_per_second,
+                burst: 1,
+            })
+        }
+
+        // A burst of zero would never hold a whole token, so it is refused
+        pub fn with_burst(mut self, burst: u32) -> Result<Self, FplDataError> {
+            if burst == 0 {
+                return Err(FplDataError::Config {
+                    setting: String::from("rate limit burst"),
+                    message: String::from("expected a burst of at least one request, got 0"),
+                });
+            }
+            self.burst = burst;
+            Ok(self)
+        }
+
+        pub fn requests_per_second(&self) -> f64 {
+            self.requests_per_second
+        }
+
+        pub fn burst(&self) -> u32 {
+            self.burst
+        }
+    }
+
+    pub struct RateLimiter {
+        limit: RateLimit,
+        bucket: Mutex<(f64, Instant)>, // Tokens available, and when they were last topped up
+    }
+
+    impl RateLimiter {
+        pub fn new(limit: RateLimit) -> Self {
+            RateLimiter {
+                limit,
+                bucket: Mutex::new((limit.burst as f64, Instant::now())),
+            }
+        }
+
+        pub fn limit(&self) -> RateLimit {
+            self.limit
+        }
+
+        // Wait until a token is available and take it
+        pub async fn acquire(&self) {
+            loop {
+                let wait = {
+                    let mut bucket = self.bucket.lock().await;
+                    let (tokens, last_refill) = &mut *bucket;
+                    let now = Instant::now();
+                    let refill = now.duration_since(*last_refill).as_secs_f64()
+                        * self.limit.requests_per_second;
+                    *tokens = (*tokens + refill).min(self.limit.burst as f64);
+                    *last_refill = now;
+
+                    if *tokens >= 1.0 {
+                        *tokens -= 1.0;
+                        return;
+                    }
+                    Duration::from_secs_f64((1.0 - *tokens) / self.limit.requests_per_second)
+                };
+                tokio::time::sleep(wait).await;
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RetryPolicy {
+        pub max_retries: u32,
+        pub base_delay: Duration,
+        pub max_delay: Duration,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            RetryPolicy {
+                max_retries: 3,
+                base_delay: Duration::from_millis(500),
+                max_delay: Duration::from_secs(30),
+            }
+        }
+    }
+
+    impl RetryPolicy {
+        pub fn none() -> Self {
+            RetryPolicy {
+                max_retries: 0,
+                ..RetryPolicy::default()
+            }
+        }
+
+        // Rate limiting and server hiccups are worth another go; a 404 or bad json is not
+        pub fn is_retryable(result: &Result<FplResponse, FplDataError>) -> bool {
+            match result {
+                Ok(response) => matches!(response.status, 429 | 500 | 502 | 503 | 504),
+                Err(FplDataError::Transport { .. }) => true,
+                Err(_) => false,
+            }
+        }
+
+        // Exponential backoff with half the delay fixed and half random, so the requests
+        // of a fan-out that failed together do not all retry at the same moment.
+        pub fn backoff(&self, attempt: u32) -> Duration {
+            let delay = self
+                .base_delay
+                .saturating_mul(2u32.saturating_pow(attempt))
+                .min(self.max_delay);
+            delay / 2 + (delay / 2).mul_f64(random_fraction())
+        }
+
+        // The server knows best how long to wait, when it says, but never longer than max_delay
+        pub fn delay(&self, attempt: u32, response: Option<&FplResponse>) -> Duration {
+            response
+                .and_then(retry_after)
+                .map(|delay| delay.min(self.max_delay))
+                .unwrap_or_else(|| self.backoff(attempt))
+        }
+    }
+
+    // Only the delay-seconds form of Retry-After is understood; an HTTP date falls back to backoff
+    pub fn retry_after(response: &FplResponse) -> Option<Duration> {
+        response
+            .header("retry-after")?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(Duration::from_secs)
+    }
+
+    // Each RandomState is seeded afresh, which is all the randomness jitter needs
+    fn random_fraction() -> f64 {
+        let hasher = RandomState::new().build_hasher();
+        (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+pub mod fpl_schema {
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt,
+    };
+
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::{Map, Value};
+
+    use crate::fpl_data::{record_error, FplDataError};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum JsonType {
+        Null,
+        Bool,
+        Number,
+        String,
+        Array,
+        Object,
+    }
+
+    impl JsonType {
+        pub fn of(value: &Value) -> JsonType {
+            match value {
+                Value::Null => JsonType::Null,
+                Value::Bool(_) => JsonType::Bool,
+                Value::Number(_) => JsonType::Number,
+                Value::String(_) => JsonType::String,
+                Value::Array(_) => JsonType::Array,
+                Value::Object(_) => JsonType::Object,
+            }
+        }
+    }
+
+    impl fmt::Display for JsonType {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let name = match self {
+                JsonType::Null => "null",
+                JsonType::Bool => "bool",
+                JsonType::Number => "number",
+                JsonType::String => "string",
+                JsonType::Array => "array",
+                JsonType::Object => "object",
+            };
+            write!(f, "{}", name)
+        }
+    }
+
+    // How the records of one type differ from the struct they are parsed into
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct RecordDrift {
+        pub appeared: BTreeSet<String>,
+        pub vanished: BTreeSet<String>,
+        pub changed_type: BTreeMap<String, (JsonType, JsonType)>, // Field -> (declared, received)
+        pub records_affected: usize,
+    }
+
+    impl RecordDrift {
+        pub fn is_empty(&self) -> bool {
+            self.appeared.is_empty() && self.vanished.is_empty() && self.changed_type.is_empty()
+        }
+
+        pub fn merge(&mut self, other: RecordDrift) {
+            self.appeared.extend(other.appeared);
+            self.vanished.extend(other.vanished);
+            self.changed_type.extend(other.changed_type);
+            self.records_affected += other.records_affected;
+        }
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct DriftReport {
+        pub records: BTreeMap<String, RecordDrift>, // Keyed by record type, e.g. "player"
+    }
+
+    impl DriftReport {
+        pub fn is_empty(&self) -> bool {
+            self.records.values().all(RecordDrift::is_empty)
+        }
+
+        pub fn record(&self, record_type: &str) -> Option<&RecordDrift> {
+            self.records.get(record_type)
+        }
+
+        pub fn merge(&mut self, other: &DriftReport) {
+            for (record_type, drift) in &other.records {
+                let summary = self.records.entry(record_type.clone()).or_default();
+                summary.merge(drift.clone());
+            }
+        }
+    }
+
+    impl fmt::Display for DriftReport {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (record_type, drift) in self.records.iter().filter(|(_, drift)| !drift.is_empty()) {
+                writeln!(f, "{} ({} records affected)", record_type, drift.records_affected)?;
+                for field in &drift.appeared {
+                    writeln!(f, "  appeared: {}", field)?;
+                }
+                for field in &drift.vanished {
+                    writeln!(f, "  vanished: {}", field)?;
+                }
+                for (field, (declared, received)) in &drift.changed_type {
+                    writeln!(f, "  changed type: {} ({} -> {})", field, declared, received)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    pub fn convert_records_tolerant<T>(
+        record_type: &str,
+        json: Value,
+        drift: &mut DriftReport,
+    ) -> Result<Vec<T>, FplDataError>
+    where
+        T: DeserializeOwned + Serialize + Default,
+    {
+        // The schema is the same for every record, so it is only worked out once
+        let schema = schema_of::<T>();
+        if let Value::Array(records) = json {
+            records
+                .into_iter()
+                .map(|record| convert_with_schema(record_type, record, schema.as_ref(), drift))
+                .collect()
+        } else {
+            Err(FplDataError::JsonShape {
+                context: format!("{} list", record_type),
+                message: String::from("expected a json array"),
+            })
+        }
+    }
+
+    // Parse a record against the declared schema, which is read off the serialized default
+    // value. Missing fields and fields of the wrong type are given their default, unknown
+    // fields are left for the record to capture, and every difference goes in the report.
+    pub fn convert_record_tolerant<T>(
+        record_type: &str,
+        record: Value,
+        drift: &mut DriftReport,
+    ) -> Result<T, FplDataError>
+    where
+        T: DeserializeOwned + Serialize + Default,
+    {
+        convert_with_schema(record_type, record, schema_of::<T>().as_ref(), drift)
+    }
+
+    // Parse the record or list of records under `field` tolerantly and put it back in its
+    // declared shape. Drift inside a nested record is then reported against its own type,
+    // rather than resetting the whole field of the record around it.
+    pub fn convert_nested_tolerant<T>(
+        record_type: &str,
+        record: &mut Map<String, Value>,
+        field: &str,
+        drift: &mut DriftReport,
+    ) -> Result<(), FplDataError>
+    where
+        T: DeserializeOwned + Serialize + Default,
+    {
+        let converted = match record.remove(field) {
+            Some(records @ Value::Array(_)) => {
+                serde_json::to_value(convert_records_tolerant::<T>(record_type, records, drift)?)
+            }
+            Some(nested @ Value::Object(_)) => {
+                serde_json::to_value(convert_record_tolerant::<T>(record_type, nested, drift)?)
+            }
+            // Anything else is left for the outer record to report
+            Some(other) => Ok(other),
+            None => return Ok(()),
+        }
+        .map_err(|e| FplDataError::JsonShape {
+            context: record_type.to_string(),
+            message: e.to_string(),
+        })?;
+
+        record.insert(field.to_string(), converted);
+        Ok(())
+    }
+
+    // Whether a value has the json type of the declared default. Decimals read with
+    // `decimal_string` serialize as a numeric string ("0"), and the API may send them as
+    // either a string or a number, so both fit.
+    fn fits_declared(default: &Value, value: &Value) -> bool {
+        match (default, value) {
+            (Value::String(declared), Value::Number(_)) => declared.parse::<f64>().is_ok(),
+            _ => JsonType::of(default) == JsonType::of(value),
+        }
+    }
+
+    fn schema_of<T>() -> Option<Map<String, Value>>
+    where
+        T: Serialize + Default,
+    {
+        match serde_json::to_value(T::default()) {
+            Ok(Value::Object(schema)) => Some(schema),
+            _ => None,
+        }
+    }
+
+    fn convert_with_schema<T>(
+        record_type: &str,
+        record: Value,
+        schema: Option<&Map<String, Value>>,
+        drift: &mut DriftReport,
+    ) -> Result<T, FplDataError>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(schema) = schema else {
+            return crate::fpl_data::convert_record(record_type, record);
+        };
+        let mut record = match record {
+            Value::Object(record) => record,
+            other => {
+                return Err(FplDataError::JsonShape {
+                    context: record_type.to_string(),
+                    message: format!("expected a json object, found {}", JsonType::of(&other)),
+                })
+            }
+        };
+        let id = record.get("id").and_then(|id| id.as_u64());
+        let mut record_drift = RecordDrift::default();
+
+        for field in record.keys().filter(|field| !schema.contains_key(*field)) {
+            record_drift.appeared.insert(field.clone());
+        }
+
+        for (field, default) in schema {
+            let declared = JsonType::of(default);
+            match record.get(field) {
+                None => {
+                    record_drift.vanished.insert(field.clone());
+                    record.insert(field.clone(), default.clone());
+                }
+                // An optional field declares null, so any type may turn up in it
+                Some(value) if declared != JsonType::Null && !fits_declared(default, value) => {
+                    let received = JsonType::of(value);
+                    record_drift
+                        .changed_type
+                        .insert(field.clone(), (declared, received));
+                    record.insert(field.clone(), default.clone());
+                }
+                Some(_) => (),
+            }
+        }
+
+        // The json types can match and the value still not fit, e.g. a negative number in an
+        // unsigned field. Fall back to the default for each field serde rejects.
+        let result = loop {
+            match serde_path_to_error::deserialize(Value::Object(record.clone())) {
+                Ok(value) => break Ok(value),
+                Err(error) => {
+                    let field = error
+                        .path()
+                        .iter()
+                        .next()
+                        .map(|segment| segment.to_string())
+                        .filter(|field| {
+                            schema.contains_key(field)
+                                && !record_drift.changed_type.contains_key(field)
+                        });
+
+                    match field {
+                        Some(field) => {
+                            let declared = JsonType::of(&schema[&field]);
+                            let received = JsonType::of(&record[&field]);
+                            record_drift
+                                .changed_type
+                                .insert(field.clone(), (declared, received));
+                            record.insert(field.clone(), schema[&field].clone());
+                        }
+                        None => break Err(record_error(record_type, id, error)),
+                    }
+                }
+            }
+        };
+
+        if !record_drift.is_empty() {
+            record_drift.records_affected = 1;
+            drift
+                .records
+                .entry(record_type.to_string())
+                .or_default()
+                .merge(record_drift);
+        }
+
+        result
+    }
+}
+
+pub mod fpl_diff {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::fpl_data::{BootstrapStatic, FplApiPlayer};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SetPiece {
+        CornersAndIndirectFreekicks,
+        DirectFreekicks,
+        Penalties,
+    }
+
+    // A single difference in a player between two snapshots. Costs are in tenths of a
+    // million, like now_cost.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PlayerChange {
+        Added,
+        Removed,
+        Cost { from: u32, to: u32 },
+        Status { from: String, to: String },
+        ChanceOfPlayingThisRound { from: Option<u32>, to: Option<u32> },
+        ChanceOfPlayingNextRound { from: Option<u32>, to: Option<u32> },
+        News { from: String, to: String, added: Option<String> },
+        Team { from: u32, to: u32 },
+        SetPieceOrder { set_piece: SetPiece, from: Option<u32>, to: Option<u32> },
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PlayerDiff {
+        pub player_id: u32,
+        pub change: PlayerChange,
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct SnapshotDiff {
+        pub players: Vec<PlayerDiff>, // By player id, then in the order of PlayerChange
+    }
+
+    impl SnapshotDiff {
+        pub fn is_empty(&self) -> bool {
+            self.players.is_empty()
+        }
+
+        pub fn player(&self, player_id: u32) -> impl Iterator<Item = &PlayerChange> {
+            self.players
+                .iter()
+                .filter(move |diff| diff.player_id == player_id)
+                .map(|diff| &diff.change)
+        }
+    }
+
+    pub fn diff_bootstrap(before: &BootstrapStatic, after: &BootstrapStatic) -> SnapshotDiff {
+        diff_players(&before.elements, &after.elements)
+    }
+
+    pub fn diff_players(before: &[FplApiPlayer], after: &[FplApiPlayer]) -> SnapshotDiff {
+        fn by_id(players: &[FplApiPlayer]) -> BTreeMap<u32, &FplApiPlayer> {
+            players.iter().map(|player| (player.id, player)).collect()
+        }
+        let (before, after) = (by_id(before), by_id(after));
+        let player_ids: BTreeSet<u32> = before.keys().chain(after.keys()).cloned().collect();
+
+        let players = player_ids
+            .into_iter()
+            .flat_map(|player_id| {
+                let changes = match (before.get(&player_id), after.get(&player_id)) {
+                    (Some(before), Some(after)) => player_changes(before, after),
+                    (None, _) => vec![PlayerChange::Added],
+                    (_, None) => vec![PlayerChange::Removed],
+                };
+                changes
+                    .into_iter()
+                    .map(move |change| PlayerDiff { player_id, change })
+            })
+            .collect();
+
+        SnapshotDiff { players }
+    }
+
+    pub fn player_changes(before: &FplApiPlayer, after: &FplApiPlayer) -> Vec<PlayerChange> {
+        let mut changes = Vec::new();
+
+        if before.now_cost != after.now_cost {
+            changes.push(PlayerChange::Cost {
+                from: before.now_cost,
+                to: after.now_cost,
+            });
+        }
+        if before.status != after.status {
+            changes.push(PlayerChange::Status {
+                from: before.status.clone(),
+                to: after.status.clone(),
+            });
+        }
+        if before.chance_of_playing_this_round != after.chance_of_playing_this_round {
+            changes.push(PlayerChange::ChanceOfPlayingThisRound {
+                from: before.chance_of_playing_this_round,
+                to: after.chance_of_playing_this_round,
+            });
+        }
+        if before.chance_of_playing_next_round != after.chance_of_playing_next_round {
+            changes.push(PlayerChange::ChanceOfPlayingNextRound {
+                from: before.chance_of_playing_next_round,
+                to: after.chance_of_playing_next_round,
+            });
+        }
+        if before.news != after.news {
+            changes.push(PlayerChange::News {
+                from: before.news.clone(),
+                to: after.news.clone(),
+                added: after.news_added.clone(),
+            });
+        }
+        if before.team != after.team {
+            changes.push(PlayerChange::Team {
+                from: before.team,
+                to: after.team,
+            });
+        }
+
+        let set_pieces = [
+            (
+                SetPiece::CornersAndIndirectFreekicks,
+                before.corners_and_indirect_freekicks_order,
+                after.corners_and_indirect_freekicks_order,
+            ),
+            (
+                SetPiece::DirectFreekicks,
+                before.direct_freekicks_order,
+                after.direct_freekicks_order,
+            ),
+            (SetPiece::Penalties, before.penalties_order, after.penalties_order),
+        ];
+        for (set_piece, from, to) in set_pieces {
+            if from != to {
+                changes.push(PlayerChange::SetPieceOrder { set_piece, from, to });
+            }
+        }
+
+        changes
+    }
+}
+
+// Past seasons from the community archive of per-season CSV files, which the live API does
+// not serve. The files keep the API's field names as column headers:
+//
+//   <archive>/<season>/teams.csv
+//   <archive>/<season>/players_raw.csv
+//   <archive>/<season>/fixtures.csv
+//   <archive>/<season>/gws/merged_gw.csv
+//
+// with seasons named like `2023-24`. Each row is read as the types the API structs declare and
+// converted tolerantly, since the columns have changed over the years (older seasons have no
+// expected stats, for instance). The differences end up in the season's `drift`.
+pub mod fpl_archive {
+    use std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+    };
+
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::{Map, Value};
+
+    use crate::fpl_cache::io_error;
+    use crate::fpl_data::{
+        FplApiFixture, FplApiPlayer, FplApiPlayerHistory, FplApiTeam, FplDataError,
+    };
+    use crate::fpl_schema::{convert_record_tolerant, DriftReport};
+
+    #[derive(Debug)]
+    pub struct ArchivedSeason {
+        pub season: String,
+        pub teams: Vec<FplApiTeam>,
+        pub players: Vec<FplApiPlayer>,
+        pub fixtures: Vec<FplApiFixture>,
+        pub player_gameweeks: Vec<FplApiPlayerHistory>, // One row per player per fixture
+        pub drift: DriftReport,
+    }
+
+    impl ArchivedSeason {
+        pub fn load<P: AsRef<Path>>(archive: P, season: &str) -> Result<Self, FplDataError> {
+            let directory = archive.as_ref().join(season);
+            let mut drift = DriftReport::default();
+
+            let teams = read_records("team", &directory.join("teams.csv"), &mut drift)?;
+            let players = read_records("player", &directory.join("players_raw.csv"), &mut drift)?;
+            let fixtures = read_records("fixture", &directory.join("fixtures.csv"), &mut drift)?;
+            let player_gameweeks = read_records(
+                "player gameweek",
+                &merged_gameweeks_path(&directory),
+                &mut drift,
+            )?;
+
+            Ok(ArchivedSeason {
+                season: season.to_string(),
+                teams,
+                players,
+                fixtures,
+                player_gameweeks,
+                drift,
+            })
+        }
+
+        pub fn player_history(&self, player_id: u32) -> Vec<&FplApiPlayerHistory> {
+            self.player_gameweeks
+                .iter()
+                .filter(|record| record.element == player_id)
+                .collect()
+        }
+
+        pub fn gameweek(&self, gameweek: u32) -> Vec<&FplApiPlayerHistory> {
+            self.player_gameweeks
+                .iter()
+                .filter(|record| record.round == gameweek)
+                .collect()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct SeasonArchive {
+        pub seasons: BTreeMap<String, ArchivedSeason>, // Keyed by season, e.g. "2023-24"
+    }
+
+    impl SeasonArchive {
+        // Every season directory in the archive
+        pub fn load<P: AsRef<Path>>(archive: P) -> Result<Self, FplDataError> {
+            let archive = archive.as_ref();
+            let mut seasons = Vec::new();
+            for entry in std::fs::read_dir(archive).map_err(|e| io_error(archive, e))? {
+                let path = entry.map_err(|e| io_error(archive, e))?.path();
+                if path.join("players_raw.csv").is_file() {
+                    if let Some(season) = path.file_name().and_then(|name| name.to_str()) {
+                        seasons.push(season.to_string());
+                    }
+                }
+            }
+            SeasonArchive::load_seasons(archive, &seasons)
+        }
+
+        pub fn load_seasons<P, S>(archive: P, seasons: &[S]) -> Result<Self, FplDataError>
+        where
+            P: AsRef<Path>,
+            S: AsRef<str>,
+        {
+            let seasons = seasons
+                .iter()
+                .map(|season| {
+                    let season = season.as_ref();
+                    Ok((season.to_string(), ArchivedSeason::load(&archive, season)?))
+                })
+                .collect::<Result<_, FplDataError>>()?;
+
+            Ok(SeasonArchive { seasons })
+        }
+
+        pub fn season(&self, season: &str) -> Option<&ArchivedSeason> {
+            self.seasons.get(season)
+        }
+    }
+
+    fn merged_gameweeks_path(directory: &Path) -> PathBuf {
+        directory.join("gws").join("merged_gw.csv")
+    }
+
+    // Read each row of a CSV file as a record of the given type
+    pub fn read_records<T>(
+        record_type: &str,
+        path: &Path,
+        drift: &mut DriftReport,
+    ) -> Result<Vec<T>, FplDataError>
+    where
+        T: DeserializeOwned + Serialize + Default,
+    {
+        let schema = match serde_json::to_value(T::default()) {
+            Ok(Value::Object(schema)) => schema,
+            _ => Map::new(),
+        };
+        let mut reader = csv::Reader::from_path(path).map_err(|e| csv_error(path, e))?;
+        let headers = reader.headers().map_err(|e| csv_error(path, e))?.clone();
+
+        reader
+            .records()
+            .map(|row| {
+                let row = row.map_err(|e| csv_error(path, e))?;
+                let record: Map<String, Value> = headers
+                    .iter()
+                    .zip(row.iter())
+                    .filter_map(|(column, cell)| {
+                        Some((column.to_string(), csv_value(cell, schema.get(column))?))
+                    })
+                    .collect();
+                convert_record_tolerant(record_type, Value::Object(record), drift)
+            })
+            .collect()
+    }
+
+    // Failing to open or read the file stays an Io error; only bad rows are Csv errors
+    fn csv_error(path: &Path, error: csv::Error) -> FplDataError {
+        match error.kind() {
+            csv::ErrorKind::Io(_) => io_error(path, error),
+            _ => FplDataError::Csv {
+                path: path.display().to_string(),
+                line: error.position().map(|position| position.line()),
+                message: error.to_string(),
+            },
+        }
+    }
+
+    // A cell as the json the API would have sent, going by the type declared for its column.
+    // An empty cell is a null where the field is optional and missing otherwise.
+    fn csv_value(cell: &str, declared: Option<&Value>) -> Option<Value> {
+        match declared {
+            Some(Value::String(_)) => Some(Value::String(cell.to_string())),
+            Some(Value::Null) | None if cell.is_empty() => Some(Value::Null),
+            _ if cell.is_empty() => None,
+            Some(Value::Array(_)) => {
+                Some(python_literal(cell).unwrap_or_else(|| Value::String(cell.to_string())))
+            }
+            Some(Value::Number(declared)) if declared.is_u64() || declared.is_i64() => {
+                // Integers are sometimes written out as floats, e.g. 55.0
+                match cell.parse::<f64>() {
+                    Ok(value) if value.fract() == 0.0 && !cell.contains(['e', 'E']) => {
+                        Some(Value::from(value as i64))
+                    }
+                    _ => Some(plain_value(cell)),
+                }
+            }
+            _ => Some(plain_value(cell)),
+        }
+    }
+
+    fn plain_value(cell: &str) -> Value {
+        match cell {
+            "True" | "true" => Value::Bool(true),
+            "False" | "false" => Value::Bool(false),
+            _ => cell
+                .parse::<i64>()
+                .map(Value::from)
+                .ok()
+                .or_else(|| {
+                    cell.parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                        .map(Value::Number)
+                })
+                .unwrap_or_else(|| Value::String(cell.to_string())),
+        }
+    }
+
+    // The fixture stats are kept as a python literal rather than json, e.g.
+    // [{'identifier': 'goals_scored', 'a': [{'value': 1, 'element': 20}], 'h': []}]
+    pub fn python_literal(text: &str) -> Option<Value> {
+        let mut json = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' | '"' => {
+                    let mut string = String::new();
+                    loop {
+                        match chars.next()? {
+                            '\\' => match chars.next()? {
+                                'n' => string.push('\n'),
+                                't' => string.push('\t'),
+                                escaped => string.push(escaped),
+                            },
+                            quote if quote == c => break,
+                            other => string.push(other),
+                        }
+                    }
+                    json.push_str(&Value::String(string).to_string());
+                }
+                c if c.is_alphabetic() => {
+                    let mut word = c.to_string();
+                    while let Some(&next) = chars.peek().filter(|next| next.is_alphanumeric()) {
+                        word.push(next);
+                        chars.next();
+                    }
+                    json.push_str(match word.as_str() {
+                        "True" => "true",
+                        "False" => "false",
+                        "None" => "null",
+                        _ => return None,
+                    });
+                }
+                other => json.push(other),
+            }
+        }
+        serde_json::from_str(&json).ok()
+    }
+}
+
+// A synchronous face on the async client for programs that do not run tokio themselves.
+// Each blocking client owns a small runtime and waits on the async client inside it, so
+// caching, recording, throttling and errors all behave exactly as they do there. It must
+// not be used from inside an async runtime.
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::OnceLock;
+
+    use crate::fpl_client;
+    use crate::fpl_data::{
+        BootstrapStatic, FplApiClassicStanding, FplApiData, FplApiElementSummary, FplApiEntry,
+        FplApiEntryHistory, FplApiEntryPicks, FplApiFixture, FplApiGameweek, FplApiH2hMatch,
+        FplApiH2hStanding, FplApiLeagueStandings, FplApiLiveGameweek, FplApiPlayer,
+        FplApiPosition, FplApiQuery, FplApiTeam, FplApiTransfer, FplDataError,
+    };
+
+    pub struct FplClient {
+        client: fpl_client::FplClient,
+        runtime: tokio::runtime::Runtime,
+    }
+
+    impl FplClient {
+        pub fn new() -> Result<Self, FplDataError> {
+            FplClient::from_async(fpl_client::FplClient::new())
+        }
+
+        // Wrap an async client that has already been configured with a cache, transport, ...
+        pub fn from_async(client: fpl_client::FplClient) -> Result<Self, FplDataError> {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| FplDataError::Runtime {
+                    message: e.to_string(),
+                })?;
+            Ok(FplClient { client, runtime })
+        }
+
+        pub fn client(&self) -> &fpl_client::FplClient {
+            &self.client
+        }
+
+        pub fn send(&self, endpoint: &str) -> Result<fpl_client::FplResponse, FplDataError> {
+            self.runtime.block_on(self.client.send(endpoint))
+        }
+
+        pub fn api_call(&self, endpoint: &str) -> Result<serde_json::Value, FplDataError> {
+            self.runtime.block_on(self.client.api_call(endpoint))
+        }
+
+        pub fn get_component<T>(&self) -> Result<T::JsonExtractType, FplDataError>
+        where
+            T: FplApiData,
+        {
+            self.runtime.block_on(self.client.get_component::<T>())
+        }
+
+        pub fn get_query<T>(&self, params: &T::Params) -> Result<T, FplDataError>
+        where
+            T: FplApiQuery,
+        {
+            self.runtime.block_on(self.client.get_query::<T>(params))
+        }
+
+        pub fn get_all_data(&self) -> Result<serde_json::Value, FplDataError> {
+            self.runtime.block_on(self.client.get_all_data())
+        }
+
+        pub fn get_bootstrap_static(&self) -> Result<BootstrapStatic, FplDataError> {
+            self.runtime.block_on(self.client.get_bootstrap_static())
+        }
+
+        pub fn get_events(&self) -> Result<Vec<FplApiGameweek>, FplDataError> {
+            self.runtime.block_on(self.client.get_events())
+        }
+
+        pub fn get_players(&self) -> Result<Vec<FplApiPlayer>, FplDataError> {
+            self.runtime.block_on(self.client.get_players())
+        }
+
+        pub fn get_positions(&self) -> Result<Vec<FplApiPosition>, FplDataError> {
+            self.runtime.block_on(self.client.get_positions())
+        }
+
+        pub fn get_player_count(&self) -> Result<usize, FplDataError> {
+            self.runtime.block_on(self.client.get_player_count())
+        }
+
+        pub fn get_teams(&self) -> Result<Vec<FplApiTeam>, FplDataError> {
+            self.runtime.block_on(self.client.get_teams())
+        }
+
+        pub fn get_fixtures(&self) -> Result<Vec<FplApiFixture>, FplDataError> {
+            self.runtime.block_on(self.client.get_fixtures())
+        }
+
+        pub fn get_element_summary(
+            &self,
+            player_id: u32,
+        ) -> Result<FplApiElementSummary, FplDataError> {
+            self.runtime.block_on(self.client.get_element_summary(player_id))
+        }
+
+        pub fn get_live_gameweek(&self, gameweek: u32) -> Result<FplApiLiveGameweek, FplDataError> {
+            self.runtime.block_on(self.client.get_live_gameweek(gameweek))
+        }
+
+        pub fn get_entry(&self, entry_id: u32) -> Result<FplApiEntry, FplDataError> {
+            self.runtime.block_on(self.client.get_entry(entry_id))
+        }
+
+        pub fn get_entry_history(&self, entry_id: u32) -> Result<FplApiEntryHistory, FplDataError> {
+            self.runtime.block_on(self.client.get_entry_history(entry_id))
+        }
+
+        pub fn get_entry_picks(
+            &self,
+            entry_id: u32,
+            gameweek: u32,
+        ) -> Result<FplApiEntryPicks, FplDataError> {
+            self.runtime.block_on(self.client.get_entry_picks(entry_id, gameweek))
+        }
+
+        pub fn get_entry_transfers(
+            &self,
+            entry_id: u32,
+        ) -> Result<Vec<FplApiTransfer>, FplDataError> {
+            self.runtime.block_on(self.client.get_entry_transfers(entry_id))
+        }
+
+        pub fn get_classic_league(
+            &self,
+            league_id: u32,
+        ) -> Result<FplApiLeagueStandings<FplApiClassicStanding>, FplDataError> {
+            self.runtime.block_on(self.client.get_classic_league(league_id))
+        }
+
+        pub fn get_h2h_league(
+            &self,
+            league_id: u32,
+        ) -> Result<FplApiLeagueStandings<FplApiH2hStanding>, FplDataError> {
+            self.runtime.block_on(self.client.get_h2h_league(league_id))
+        }
+
+        pub fn get_h2h_matches(&self, league_id: u32) -> Result<Vec<FplApiH2hMatch>, FplDataError> {
+            self.runtime.block_on(self.client.get_h2h_matches(league_id))
+        }
+    }
+
+    // The free functions share one client, and so one runtime, for the life of the program
+    pub(crate) fn default_client() -> Result<&'static FplClient, FplDataError> {
+        static DEFAULT_CLIENT: OnceLock<FplClient> = OnceLock::new();
+
+        if let Some(client) = DEFAULT_CLIENT.get() {
+            return Ok(client);
+        }
+        let client = FplClient::new()?;
+        Ok(DEFAULT_CLIENT.get_or_init(|| client))
+    }
+
+    pub fn get_component<T>() -> Result<T::JsonExtractType, FplDataError>
+    where
+        T: FplApiData,
+    {
+        default_client()?.get_component::<T>()
+    }
+
+    pub fn get_all_data() -> Result<serde_json::Value, FplDataError> {
+        default_client()?.get_all_data()
+    }
+
+    pub fn get_bootstrap_static() -> Result<BootstrapStatic, FplDataError> {
+        default_client()?.get_bootstrap_static()
+    }
+
+    pub fn get_events() -> Result<Vec<FplApiGameweek>, FplDataError> {
+        default_client()?.get_events()
+    }
+
+    pub fn get_players() -> Result<Vec<FplApiPlayer>, FplDataError> {
+        default_client()?.get_players()
+    }
+
+    pub fn get_positions() -> Result<Vec<FplApiPosition>, FplDataError> {
+        default_client()?.get_positions()
+    }
+
+    pub fn get_player_count() -> Result<usize, FplDataError> {
+        default_client()?.get_player_count()
+    }
+
+    pub fn get_teams() -> Result<Vec<FplApiTeam>, FplDataError> {
+        default_client()?.get_teams()
+    }
+
+    pub fn get_fixtures() -> Result<Vec<FplApiFixture>, FplDataError> {
+        default_client()?.get_fixtures()
+    }
+
+    pub fn get_element_summary(player_id: u32) -> Result<FplApiElementSummary, FplDataError> {
+        default_client()?.get_element_summary(player_id)
+    }
+
+    pub fn get_live_gameweek(gameweek: u32) -> Result<FplApiLiveGameweek, FplDataError> {
+        default_client()?.get_live_gameweek(gameweek)
+    }
+
+    pub fn get_entry(entry_id: u32) -> Result<FplApiEntry, FplDataError> {
+        default_client()?.get_entry(entry_id)
+    }
+
+    pub fn get_entry_history(entry_id: u32) -> Result<FplApiEntryHistory, FplDataError> {
+        default_client()?.get_entry_history(entry_id)
+    }
+
+    pub fn get_entry_picks(entry_id: u32, gameweek: u32) -> Result<FplApiEntryPicks, FplDataError> {
+        default_client()?.get_entry_picks(entry_id, gameweek)
+    }
+
+    pub fn get_entry_transfers(entry_id: u32) -> Result<Vec<FplApiTransfer>, FplDataError> {
+        default_client()?.get_entry_transfers(entry_id)
+    }
+
+    pub fn get_classic_league(
+        league_id: u32,
+    ) -> Result<FplApiLeagueStandings<FplApiClassicStanding>, FplDataError> {
+        default_client()?.get_classic_league(league_id)
+    }
+
+    pub fn get_h2h_league(
+        league_id: u32,
+    ) -> Result<FplApiLeagueStandings<FplApiH2hStanding>, FplDataError> {
+        default_client()?.get_h2h_league(league_id)
+    }
+
+    pub fn get_h2h_matches(league_id: u32) -> Result<Vec<FplApiH2hMatch>, FplDataError> {
+        default_client()?.get_h2h_matches(league_id)
+    }
+}
+
+// A stand-in for the FPL API that serves JSON snapshot files over plain HTTP, so the
+// client (and anything built on it) can run without the real service.
+//
+// Snapshot files are named the way the cache names them (`endpoint_file_name`), e.g.
+// `bootstrap-static.json` or `entry_1_event_2_picks.json`. Files that change as the season
+// goes on can be put in `gameweek-N` subdirectories: the server answers from the latest
+// such directory at or before its current gameweek, falling back to the top level.
+pub mod fpl_mock_server {
+    use std::{
+        net::SocketAddr,
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+    };
+
+    use async_trait::async_trait;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        task::JoinHandle,
+    };
+
+    use crate::fpl_cache::{endpoint_file_name, io_error};
+    use crate::fpl_client::{FplClient, FplRequest, FplResponse, FplTransport};
+    use crate::fpl_data::FplDataError;
+
+    pub const API_PREFIX: &str = "/api/";
+    const MAX_REQUEST_HEADER: usize = 16 * 1024;
+
+    pub struct MockSnapshots {
+        directory: PathBuf,
+        gameweek: AtomicU32, // 0 before the season starts
+    }
+
+    impl MockSnapshots {
+        // Starts at the gameweek the snapshot's bootstrap-static marks as current
+        pub fn new<P: AsRef<Path>>(directory: P) -> Self {
+            let directory = directory.as_ref().to_path_buf();
+            let gameweek = std::fs::read_to_string(directory.join("bootstrap-static.json"))
+                .ok()
+                .and_then(|body| serde_json::from_str::<serde_json::Value>(&body).ok())
+                .and_then(|bootstrap| {
+                    bootstrap["events"]
+                        .as_array()?
+                        .iter()
+                        .find(|event| event["is_current"] == true)?["id"]
+                        .as_u64()
+                })
+                .unwrap_or(0) as u32;
+
+            MockSnapshots {
+                directory,
+                gameweek: AtomicU32::new(gameweek),
+            }
+        }
+
+        pub fn with_gameweek(self, gameweek: u32) -> Self {
+            self.set_gameweek(gameweek);
+            self
+        }
+
+        pub fn directory(&self) -> &Path {
+            &self.directory
+        }
+
+        pub fn gameweek(&self) -> u32 {
+            self.gameweek.load(Ordering::SeqCst)
+        }
+
+        pub fn set_gameweek(&self, gameweek: u32) {
+            self.gameweek.store(gameweek, Ordering::SeqCst);
+        }
+
+        // Move on to the next gameweek, returning it
+        pub fn advance(&self) -> u32 {
+            self.gameweek.fetch_add(1, Ordering::SeqCst) + 1
+        }
+
+        pub fn snapshot_path(&self, endpoint: &str) -> Option<PathBuf> {
+            let file_name = endpoint_file_name(endpoint);
+            (1..=self.gameweek())
+                .rev()
+                .map(|gameweek| {
+                    self.directory
+                        .join(format!("gameweek-{}", gameweek))
+                        .join(&file_name)
+                })
+                .chain(std::iter::once(self.directory.join(&file_name)))
+                .find(|path| path.is_file())
+        }
+
+        // The response the API would give for an endpoint at the current gameweek
+        pub async fn response(&self, endpoint: &str) -> Result<FplResponse, FplDataError> {
+            let endpoint = endpoint.trim_start_matches('/');
+            let path = match self.snapshot_path(endpoint) {
+                Some(path) => path,
+                None => return Ok(FplResponse::new(404, "The game is being updated.")),
+            };
+            let body = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| io_error(&path, e))?;
+
+            let body = if endpoint.split('?').next() == Some("bootstrap-static/") {
+                self.with_gameweek_flags(body)
+            } else {
+                body
+            };
+            Ok(FplResponse::new(200, &body).with_header("content-type", "application/json"))
+        }
+
+        // Rewrite the event flags in bootstrap-static to match the simulated gameweek
+        fn with_gameweek_flags(&self, body: String) -> String {
+            let mut bootstrap: serde_json::Value = match serde_json::from_str(&body) {
+                Ok(bootstrap) => bootstrap,
+                Err(_) => return body,
+            };
+            let gameweek = self.gameweek() as u64;
+
+            if let Some(events) = bootstrap["events"].as_array_mut() {
+                for event in events {
+                    let id = match event["id"].as_u64() {
+                        Some(id) => id,
+                        None => continue,
+                    };
+                    // The current gameweek keeps whatever state it was recorded in
+                    let finished = id < gameweek || (id == gameweek && event["finished"] == true);
+                    let data_checked =
+                        id < gameweek || (id == gameweek && event["data_checked"] == true);
+                    event["finished"] = finished.into();
+                    event["data_checked"] = data_checked.into();
+                    event["is_previous"] = (id + 1 == gameweek).into();
+                    event["is_current"] = (id == gameweek).into();
+                    event["is_next"] = (id == gameweek + 1).into();
+                }
+            }
+            bootstrap.to_string()
+        }
+
+        // API requests are GETs under /api/, the rest are the server's own controls:
+        // `GET /mock/gameweek`, `POST /mock/advance` and `POST /mock/gameweek/{n}`.
+        pub async fn handle(&self, method: &str, path: &str) -> Result<FplResponse, FplDataError> {
+            let gameweek_response =
+                |gameweek: u32| FplResponse::new(200, &format!("{{\"gameweek\": {}}}", gameweek));
+
+            match (method, path) {
+                ("GET", path) if path.starts_with(API_PREFIX) => {
+                    self.response(&path[API_PREFIX.len()..]).await
+                }
+                ("GET", "/mock/gameweek") => Ok(gameweek_response(self.gameweek())),
+                ("POST", "/mock/advance") => Ok(gameweek_response(self.advance())),
+                ("POST", path) if path.starts_with("/mock/gameweek/") => {
+                    match path["/mock/gameweek/".len()..].parse() {
+                        Ok(gameweek) => {
+                            self.set_gameweek(gameweek);
+                            Ok(gameweek_response(gameweek))
+                        }
+                        Err(_) => Ok(FplResponse::new(400, "Invalid gameweek")),
+                    }
+                }
+                _ => Ok(FplResponse::new(404, "Not found")),
+            }
+        }
+    }
+
+    // Lets a client read the snapshots directly, without a server in between
+    #[async_trait]
+    impl FplTransport for MockSnapshots {
+        async fn send(&self, request: FplRequest) -> Result<FplResponse, FplDataError> {
+            self.response(&request.endpoint).await
+        }
+    }
+
+    pub struct MockServer {
+        address: SocketAddr,
+        snapshots: Arc<MockSnapshots>,
+        task: JoinHandle<()>,
+    }
+
+    impl MockServer {
+        // Use port 0 to let the system pick a free port
+        pub async fn start(snapshots: MockSnapshots, address: &str) -> Result<Self, FplDataError> {
+            let server_error = |e: std::io::Error| FplDataError::Server {
+                address: address.to_string(),
+                message: e.to_string(),
+            };
+            let listener = TcpListener::bind(address).await.map_err(server_error)?;
+            let address = listener.local_addr().map_err(server_error)?;
+            let snapshots = Arc::new(snapshots);
+            let task = tokio::spawn(serve(listener, snapshots.clone()));
+
+            Ok(MockServer {
+                address,
+                snapshots,
+                task,
+            })
+        }
+
+        pub fn address(&self) -> SocketAddr {
+            self.address
+        }
+
+        pub fn base_url(&self) -> String {
+            format!("http://{}{}", self.address, API_PREFIX)
+        }
+
+        pub fn snapshots(&self) -> &MockSnapshots {
+            &self.snapshots
+        }
+
+        // A client pointed at this server instead of the real API
+        pub fn client(&self) -> FplClient {
+            FplClient::new().with_base_url(&self.base_url())
+        }
+
+        // Serve until the task is cancelled; dropping the server stops it too
+        pub async fn wait(mut self) {
+            let _ = (&mut self.task).await;
+        }
+    }
+
+    impl Drop for MockServer {
+        fn drop(&mut self) {
+            self.task.abort();
+        }
+    }
+
+    async fn serve(listener: TcpListener, snapshots: Arc<MockSnapshots>) {
+        loop {
+            if let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle_connection(stream, snapshots.clone()));
+            }
+        }
+    }
+
+    // One request per connection, which is all the client needs
+    async fn handle_connection(mut stream: TcpStream, snapshots: Arc<MockSnapshots>) {
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+            match stream.read(&mut buffer).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => request.extend_from_slice(&buffer[..read]),
+            }
+            if request.len() > MAX_REQUEST_HEADER {
+                return;
+            }
+        }
+
+        let request = String::from_utf8_lossy(&request);
+        let mut request_line = request.lines().next().unwrap_or("").split_whitespace();
+        let method = request_line.next().unwrap_or("");
+        let path = request_line.next().unwrap_or("");
+
+        let response = snapshots
+            .handle(method, path)
+            .await
+            .unwrap_or_else(|error| FplResponse::new(500, &error.to_string()));
+
+        let mut message = format!(
+            "HTTP/1.1 {} {}\r\ncontent-length: {}\r\nconnection: close\r\n",
+            response.status,
+            reason_phrase(response.status),
+            response.body.len()
+        );
+        for (name, value) in &response.headers {
+            message.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        message.push_str("\r\n");
+        message.push_str(&response.body);
+
+        let _ = stream.write_all(message.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+
+    // The statuses the mock server and the FPL API use, and otherwise one for the class
+    pub(crate) fn reason_phrase(status: u16) -> &'static str {
+        match status {
+            200 => "OK",
+            304 => "Not Modified",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            429 => "Too Many Requests",
+            500 => "Internal Server Error",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => match status / 100 {
+                1 => "Informational",
+                2 => "Success",
+                3 => "Redirection",
+                4 => "Client Error",
+                _ => "Server Error",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use async_trait::async_trait;
+
+    use super::{
+        fpl_archive, fpl_cache, fpl_client, fpl_data, fpl_diff, fpl_mock_server, fpl_recording,
+        fpl_schema, fpl_throttle,
+    };
+
+    // Serves the fixtures list with an ETag, answers 304 when revalidated, and keeps every request
+    #[derive(Default)]
+    struct RevalidatingTransport {
+        requests: Mutex<Vec<fpl_client::FplRequest>>,
+    }
+
+    #[async_trait]
+    impl fpl_client::FplTransport for RevalidatingTransport {
+        async fn send(
+            &self,
+            request: fpl_client::FplRequest,
+        ) -> Result<fpl_client::FplResponse, fpl_data::FplDataError> {
+            let revalidating = request
+                .headers
+                .iter()
+                .any(|(name, value)| name == "if-none-match" && value.starts_with("\"v"));
+            self.requests.lock().unwrap().push(request);
+
+            // Unchanged, but the server has moved on to a new validator
+            if revalidating {
+                Ok(fpl_client::FplResponse::new(304, "").with_header("ETag", "\"v2\""))
+            } else {
+                Ok(fpl_client::FplResponse::new(200, "[]").with_header("ETag", "\"v1\""))
+            }
+        }
+    }
+
+    const SYNTHETIC_SEASON: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/synthetic-2024-25");
+
+    #[cfg(feature = "live-tests")]
+    #[tokio::test]
+    async fn test_get_data() {
+        let _ = fpl_data::get_all_data().await;
+    }
+
+    #[tokio::test]
+    async fn test_synthetic_season() {
+        let client = fpl_client::FplClient::new()
+            .with_transport(fpl_mock_server::MockSnapshots::new(SYNTHETIC_SEASON));
+
+        let bootstrap = client.get_bootstrap_static().await.unwrap();
+        assert_eq!(bootstrap.teams.len(), 20);
+        assert_eq!(bootstrap.elements.len(), 60);
+        assert_eq!(bootstrap.events.len(), 38);
+        assert!(bootstrap.events[1].is_current && bootstrap.events[1].finished);
+
+        let fixtures = client.get_fixtures().await.unwrap();
+        assert_eq!(fixtures.len(), 30);
+        assert_eq!(fixtures.iter().filter(|fixture| fixture.finished).count(), 20);
+
+        // Endpoints with no snapshot answer like the API does mid-update
+        assert!(matches!(
+            client.get_live_gameweek(3).await,
+            Err(fpl_data::FplDataError::HttpStatus { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_uses_base_url_and_transport() {
+        let transport = fpl_client::InMemoryTransport::new().with_json(
+            "bootstrap-static/",
+            r#"{"events": [], "teams": [], "total_players": 11000000, "elements": [], "element_types": []}"#,
+        );
+        let client = fpl_client::FplClient::new()
+            .with_base_url("http://localhost:8080/api")
+            .with_transport(transport);
+
+        assert_eq!(
+            client.endpoint_url("fixtures/"),
+            "http://localhost:8080/api/fixtures/"
+        );
+        assert_eq!(client.get_player_count().await.unwrap(), 11000000);
+        assert!(client.get_teams().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_static_views() {
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("bootstrap-static/", include_str!("../test_data/bootstrap-static.json"));
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        let bootstrap = client.get_bootstrap_static().await.unwrap();
+
+        assert_eq!(bootstrap.player_count(), 10832548);
+        assert_eq!(bootstrap.events().len(), 2);
+        assert_eq!(bootstrap.positions().len(), 4);
+        assert_eq!(bootstrap.get_team(1).unwrap().short_name, "ARS");
+        assert_eq!(bootstrap.get_player(351).unwrap().web_name, "Haaland");
+        assert_eq!(bootstrap.phases.len(), 2);
+        assert_eq!(bootstrap.chips.len(), 1);
+        assert_eq!(bootstrap.game_settings.squad_squadsize, 15);
+        assert_eq!(bootstrap.game_settings.squad_team_limit, 3);
+        assert_eq!(bootstrap.phases[1].name, "August");
+        assert_eq!(bootstrap.phases[1].stop_event, 3);
+        assert_eq!(bootstrap.chips[0].name, "wildcard");
+        assert_eq!(bootstrap.chips[0].overrides.pick_multiplier, None);
+
+        assert_eq!(client.get_players().await.unwrap().len(), bootstrap.players().len());
+    }
+
+    #[tokio::test]
+    async fn test_client_reports_http_status() {
+        let client = fpl_client::FplClient::new()
+            .with_transport(fpl_client::InMemoryTransport::new());
+
+        let error = client.get_fixtures().await.unwrap_err();
+        assert_eq!(
+            error,
+            fpl_data::FplDataError::HttpStatus {
+                url: String::from("https://fantasy.premierleague.com/api/fixtures/"),
+                status: 404
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_error_reports_id_and_field() {
+        let mut players: serde_json::Value = serde_json::from_str::<serde_json::Value>(
+            include_str!("../test_data/bootstrap-static.json"),
+        )
+        .unwrap()["elements"]
+            .take();
+        players[1]["now_cost"] = serde_json::Value::from("expensive");
+
+        let error = fpl_data::convert_records::<fpl_data::FplApiPlayer>("player", players.clone())
+            .unwrap_err();
+        match error {
+            fpl_data::FplDataError::Record { id, field, .. } => {
+                assert_eq!(id, Some(351));
+                assert_eq!(field.as_deref(), Some("now_cost"));
+            }
+            _ => panic!("Expected a record error, got {:?}", error),
+        }
+
+        players[1].as_object_mut().unwrap().remove("web_name");
+        players[1]["now_cost"] = serde_json::Value::from(150);
+        let error = fpl_data::convert_records::<fpl_data::FplApiPlayer>("player", players)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to convert player 351 (field web_name): missing field `web_name`"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_serves_fresh_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let transport = std::sync::Arc::new(RevalidatingTransport::default());
+        let client = fpl_client::FplClient::new()
+            .with_transport(transport.clone())
+            .with_cache(fpl_cache::FplCache::new(directory.path()));
+
+        assert!(client.get_fixtures().await.unwrap().is_empty());
+        assert!(client.get_fixtures().await.unwrap().is_empty());
+
+        assert_eq!(transport.requests.lock().unwrap().len(), 1);
+        assert!(directory
+            .path()
+            .join(fpl_cache::cache_file_name(&client.endpoint_url("fixtures/")))
+            .exists());
+
+        // Another server's responses are kept apart even in the same directory
+        let other_client = fpl_client::FplClient::new()
+            .with_base_url("http://localhost:8080/api")
+            .with_transport(transport.clone())
+            .with_cache(fpl_cache::FplCache::new(directory.path()));
+        other_client.get_fixtures().await.unwrap();
+        assert_eq!(transport.requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_revalidates_stale_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let ttl = fpl_cache::CacheTtl {
+            fixtures: Duration::ZERO,
+            ..fpl_cache::CacheTtl::default()
+        };
+        let transport = std::sync::Arc::new(RevalidatingTransport::default());
+        let client = fpl_client::FplClient::new()
+            .with_transport(transport.clone())
+            .with_cache(fpl_cache::FplCache::new(directory.path()).with_ttl(ttl));
+
+        client.get_fixtures().await.unwrap();
+        let response = client.send("fixtures/").await.unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "[]");
+        assert_eq!(response.header("etag"), Some("\"v2\""));
+
+        // The validator from the 304 is the one sent next time
+        client.send("fixtures/").await.unwrap();
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[2]
+            .headers
+            .contains(&(String::from("if-none-match"), String::from("\"v2\""))));
+    }
+
+    #[test]
+    fn test_cache_ttl_and_file_names() {
+        let ttl = fpl_cache::CacheTtl::default();
+        assert_eq!(ttl.for_endpoint("bootstrap-static/"), ttl.bootstrap_static);
+        assert_eq!(ttl.for_endpoint("fixtures/?event=3"), ttl.fixtures);
+        assert_eq!(ttl.for_endpoint("event/12/live/"), ttl.live);
+        assert_eq!(ttl.for_endpoint("element-summary/3/"), ttl.default);
+
+        assert_eq!(
+            fpl_cache::endpoint_file_name("leagues-classic/314/standings/?page_standings=2"),
+            "leagues-classic_314_standings_page_standings_2.json"
+        );
+
+        assert_eq!(
+            fpl_cache::cache_file_name("https://example.com/api/fixtures/?event=3"),
+            "https_3A_2F_2Fexample.com_2Fapi_2Ffixtures_2F_3Fevent_3D3.json"
+        );
+        assert_ne!(
+            fpl_cache::cache_file_name("https://example.com/api/a/b_c"),
+            fpl_cache::cache_file_name("https://example.com/api/a_b/c")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay_session() {
+        let directory = tempfile::tempdir().unwrap();
+        let bootstrap = include_str!("../test_data/bootstrap-static.json");
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("bootstrap-static/", bootstrap)
+            .with_json("fixtures/", "[]");
+
+        let recorder = fpl_recording::FplRecorder::new(directory.path()).unwrap();
+        let client = fpl_client::FplClient::new()
+            .with_transport(transport)
+            .with_recorder(recorder);
+        let recorded_players = client.get_players().await.unwrap();
+        client.get_fixtures().await.unwrap();
+
+        let recordings = fpl_recording::load_recordings(directory.path()).unwrap();
+        assert_eq!(recordings.len(), 2);
+        assert_eq!(recordings[0].endpoint, "bootstrap-static/");
+        assert_eq!(recordings[0].body, bootstrap);
+
+        let replay = fpl_client::FplClient::replay(directory.path()).unwrap();
+        let replayed_players = replay.get_players().await.unwrap();
+        assert_eq!(replayed_players.len(), recorded_players.len());
+        assert_eq!(replay.send("bootstrap-static/").await.unwrap().body, bootstrap);
+        assert!(replay.get_teams().await.is_ok());
+
+        match replay.send("event/1/live/").await.unwrap_err() {
+            fpl_data::FplDataError::Transport { .. } => (),
+            error => panic!("Expected a transport error, got {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_returns_responses_in_order() {
+        let recording = |sequence: u64, body: &str| fpl_recording::RecordedResponse {
+            sequence,
+            recorded_at: 0,
+            endpoint: String::from("fixtures/"),
+            status: 200,
+            headers: Default::default(),
+            body: body.to_string(),
+        };
+        let transport = fpl_recording::ReplayTransport::from_recordings(vec![
+            recording(0, "[1]"),
+            recording(1, "[2]"),
+        ]);
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        assert_eq!(client.send("fixtures/").await.unwrap().body, "[1]");
+        assert_eq!(client.send("fixtures/").await.unwrap().body, "[2]");
+        assert_eq!(client.send("fixtures/").await.unwrap().body, "[2]");
+    }
+
+    #[tokio::test]
+    async fn test_get_element_summary() {
+        let transport = fpl_client::InMemoryTransport::new().with_json(
+            "element-summary/351/",
+            include_str!("../test_data/element-summary-351.json"),
+        );
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        let summary = client.get_element_summary(351).await.unwrap();
+
+        assert_eq!(summary.history.len(), 2);
+        assert_eq!(summary.history[1].goals_scored, 3);
+        assert_eq!(summary.fixtures[0].event, Some(3));
+        assert!(summary.fixtures[0].is_home);
+        assert_eq!(summary.history_past[0].season_name, "2022/23");
+        assert_eq!(summary.history_past[0].element_code, 223094);
+    }
+
+    #[tokio::test]
+    async fn test_get_live_gameweek() {
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("event/1/live/", include_str!("../test_data/event-1-live.json"));
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        let live = client.get_live_gameweek(1).await.unwrap();
+
+        assert_eq!(live.elements.len(), 2);
+        assert_eq!(live.elements[0].explain.len(), 2);
+        assert_eq!(live.elements[1].stats.total_points, 9);
+        assert_eq!(live.elements[1].explain[0].fixture, 10);
+        assert_eq!(live.elements[1].explain[0].stats[1].identifier, "goals_scored");
+    }
+
+    #[tokio::test]
+    async fn test_get_entry_endpoints() {
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("entry/1/", include_str!("../test_data/entry-1.json"))
+            .with_json("entry/1/history/", include_str!("../test_data/entry-1-history.json"))
+            .with_json(
+                "entry/1/event/2/picks/",
+                include_str!("../test_data/entry-1-event-2-picks.json"),
+            )
+            .with_json("entry/1/transfers/", include_str!("../test_data/entry-1-transfers.json"));
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        let entry = client.get_entry(1).await.unwrap();
+        assert_eq!(entry.name, "Expected Toulouse");
+        assert_eq!(entry.leagues.classic[0].id, 314);
+
+        let history = client.get_entry_history(1).await.unwrap();
+        assert_eq!(history.current[1].total_points, 141);
+        assert_eq!(history.chips[0].name, "bboost");
+
+        let picks = client.get_entry_picks(1, 2).await.unwrap();
+        assert_eq!(picks.picks.len(), 15);
+        assert_eq!(picks.active_chip.as_deref(), Some("bboost"));
+
+        let transfers = client.get_entry_transfers(1).await.unwrap();
+        assert_eq!(transfers[0].element_in, 400);
+    }
+
+    #[tokio::test]
+    async fn test_get_league_walks_pages() {
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json(
+                "leagues-classic/314/standings/?page_standings=1",
+                include_str!("../test_data/leagues-classic-314-standings-1.json"),
+            )
+            .with_json(
+                "leagues-classic/314/standings/?page_standings=2",
+                include_str!("../test_data/leagues-classic-314-standings-2.json"),
+            )
+            .with_json(
+                "leagues-h2h/1234/standings/?page_standings=1",
+                include_str!("../test_data/leagues-h2h-1234-standings-1.json"),
+            )
+            .with_json(
+                "leagues-h2h-matches/league/1234/?page=1",
+                include_str!("../test_data/leagues-h2h-matches-1234-1.json"),
+            );
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        let classic = client.get_classic_league(314).await.unwrap();
+        assert_eq!(classic.league.name, "Office League");
+        let entries: Vec<u32> = classic.standings.iter().map(|row| row.entry).collect();
+        assert_eq!(entries, vec![2, 1, 3]);
+
+        let h2h = client.get_h2h_league(1234).await.unwrap();
+        assert_eq!(h2h.standings[0].matches_won, 1);
+
+        let matches = client.get_h2h_matches(1234).await.unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].winner, Some(2));
+    }
+
+    // Answers with the queued responses in turn, then 200s, and tracks how many requests overlap
+    #[derive(Default)]
+    struct FlakyTransport {
+        responses: Mutex<Vec<fpl_client::FplResponse>>,
+        calls: Mutex<u32>,
+        in_flight: Mutex<(u32, u32)>, // Current and highest seen
+    }
+
+    #[async_trait]
+    impl fpl_client::FplTransport for FlakyTransport {
+        async fn send(
+            &self,
+            _request: fpl_client::FplRequest,
+        ) -> Result<fpl_client::FplResponse, fpl_data::FplDataError> {
+            *self.calls.lock().unwrap() += 1;
+            {
+                let mut in_flight = self.in_flight.lock().unwrap();
+                in_flight.0 += 1;
+                in_flight.1 = in_flight.1.max(in_flight.0);
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.lock().unwrap().0 -= 1;
+
+            let mut responses = self.responses.lock().unwrap();
+            if responses.is_empty() {
+                Ok(fpl_client::FplResponse::new(200, "[]"))
+            } else {
+                Ok(responses.remove(0))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_honour_retry_after() {
+        let transport = std::sync::Arc::new(FlakyTransport {
+            responses: Mutex::new(vec![
+                fpl_client::FplResponse::new(429, "").with_header("Retry-After", "0"),
+                fpl_client::FplResponse::new(503, ""),
+            ]),
+            ..FlakyTransport::default()
+        });
+        let client = fpl_client::FplClient::new()
+            .with_transport(transport.clone())
+            .with_retry_policy(fpl_throttle::RetryPolicy {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+            });
+
+        assert!(client.get_fixtures().await.unwrap().is_empty());
+        assert_eq!(*transport.calls.lock().unwrap(), 3);
+
+        // Not found is not retried, and retries stop once the policy is used up
+        let client = client.with_transport(fpl_client::InMemoryTransport::new());
+        assert!(client.get_fixtures().await.is_err());
+
+        let transport = std::sync::Arc::new(FlakyTransport {
+            responses: Mutex::new(vec![fpl_client::FplResponse::new(500, ""); 3]),
+            ..FlakyTransport::default()
+        });
+        let retry_policy = fpl_throttle::RetryPolicy {
+            max_retries: 1,
+            ..client.retry_policy().clone()
+        };
+        let client = client
+            .with_transport(transport.clone())
+            .with_retry_policy(retry_policy);
+        assert_eq!(
+            client.get_fixtures().await.unwrap_err(),
+            fpl_data::FplDataError::HttpStatus {
+                url: client.endpoint_url("fixtures/"),
+                status: 500
+            }
+        );
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_backoff_grows_with_jitter() {
+        let policy = fpl_throttle::RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+        };
+
+        for (attempt, full_delay) in [(0, 100), (1, 200), (2, 300), (4, 300)] {
+            let delay = policy.backoff(attempt);
+            assert!(delay >= Duration::from_millis(full_delay / 2));
+            assert!(delay <= Duration::from_millis(full_delay));
+        }
+
+        let response = fpl_client::FplResponse::new(429, "").with_header("Retry-After", "7");
+        assert_eq!(
+            fpl_throttle::RetryPolicy::default().delay(0, Some(&response)),
+            Duration::from_secs(7)
+        );
+        // A Retry-After longer than the policy allows is cut short
+        assert_eq!(policy.delay(0, Some(&response)), Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_and_concurrency_are_shared_by_clones() {
+        let transport = std::sync::Arc::new(FlakyTransport::default());
+        let client = fpl_client::FplClient::new()
+            .with_transport(transport.clone())
+            .with_max_concurrency(2)
+            .unwrap()
+            .with_rate_limit(
+                fpl_throttle::RateLimit::per_second(100.0).unwrap().with_burst(4).unwrap(),
+            );
+
+        let start = std::time::Instant::now();
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.get_fixtures().await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        assert_eq!(transport.in_flight.lock().unwrap().1, 2);
+        // Four go on the burst, the other four wait 10ms each for a token
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert_eq!(client.max_concurrency(), Some(2));
+        assert_eq!(client.rate_limit().unwrap().burst(), 4);
+
+        // A limit that could never let a request through is refused up front
+        assert!(fpl_throttle::RateLimit::per_second(0.0).is_err());
+        assert!(fpl_throttle::RateLimit::per_second(f64::NAN).is_err());
+        assert!(matches!(
+            fpl_throttle::RateLimit::per_second(2.0).unwrap().with_burst(0),
+            Err(fpl_data::FplDataError::Config { .. })
+        ));
+        assert!(matches!(
+            fpl_client::FplClient::new().with_max_concurrency(0),
+            Err(fpl_data::FplDataError::Config { .. })
+        ));
+    }
+
+    #[test]
+    fn test_diff_bootstrap_snapshots() {
+        let json: serde_json::Value =
+            serde_json::from_str(include_str!("../test_data/bootstrap-static.json")).unwrap();
+        let mut later = json.clone();
+        let elements = later["elements"].as_array_mut().unwrap();
+
+        let mut signing = elements[0].clone();
+        signing["id"] = 700.into();
+
+        let gabriel = &mut elements[0];
+        gabriel["team"] = 6.into();
+        gabriel["penalties_order"] = serde_json::Value::Null;
+        gabriel["direct_freekicks_order"] = 2.into();
+
+        let haaland = &mut elements[1];
+        haaland["now_cost"] = 151.into();
+        haaland["status"] = "d".into();
+        haaland["chance_of_playing_next_round"] = 75.into();
+        haaland["news"] = "Knock - 75% chance of playing".into();
+        haaland["news_added"] = "2024-08-27T09:30:00.000000Z".into();
+        elements.push(signing);
+
+        let before = fpl_data::BootstrapStatic::from_json(json).unwrap();
+        let after = fpl_data::BootstrapStatic::from_json(later).unwrap();
+        let diff = fpl_diff::diff_bootstrap(&before, &after);
+
+        assert_eq!(
+            diff.player(3).collect::<Vec<_>>(),
+            vec![
+                &fpl_diff::PlayerChange::Team { from: 1, to: 6 },
+                &fpl_diff::PlayerChange::SetPieceOrder {
+                    set_piece: fpl_diff::SetPiece::DirectFreekicks,
+                    from: None,
+                    to: Some(2),
+                },
+                &fpl_diff::PlayerChange::SetPieceOrder {
+                    set_piece: fpl_diff::SetPiece::Penalties,
+                    from: Some(1),
+                    to: None,
+                },
+            ]
+        );
+        assert_eq!(
+            diff.player(351).collect::<Vec<_>>(),
+            vec![
+                &fpl_diff::PlayerChange::Cost { from: 150, to: 151 },
+                &fpl_diff::PlayerChange::Status {
+                    from: String::from("a"),
+                    to: String::from("d"),
+                },
+                &fpl_diff::PlayerChange::ChanceOfPlayingNextRound {
+                    from: None,
+                    to: Some(75),
+                },
+                &fpl_diff::PlayerChange::News {
+                    from: String::new(),
+                    to: String::from("Knock - 75% chance of playing"),
+                    added: Some(String::from("2024-08-27T09:30:00.000000Z")),
+                },
+            ]
+        );
+        assert_eq!(diff.players.last().unwrap().player_id, 700);
+        assert_eq!(diff.players.last().unwrap().change, fpl_diff::PlayerChange::Added);
+
+        // Going the other way the signing disappears
+        let reverse = fpl_diff::diff_bootstrap(&after, &before);
+        assert_eq!(reverse.player(700).next(), Some(&fpl_diff::PlayerChange::Removed));
+        assert!(fpl_diff::diff_bootstrap(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_load_season_archive() {
+        let archive =
+            fpl_archive::SeasonArchive::load(concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/archive"))
+                .unwrap();
+        assert_eq!(archive.seasons.keys().collect::<Vec<_>>(), vec!["2022-23", "2023-24"]);
+
+        let season = archive.season("2023-24").unwrap();
+        assert_eq!(season.teams.len(), 4);
+        assert_eq!(season.teams[1].name, "Luton");
+        let haaland = season.players.iter().find(|player| player.id == 355).unwrap();
+        assert_eq!((haaland.web_name.as_str(), haaland.code, haaland.now_cost), ("Haaland", 223094, 140));
+        assert_eq!(haaland.penalties_order, Some(1));
+        assert_eq!(haaland.form, Some(5.0));
+
+        let opener = &season.fixtures[0];
+        assert!(opener.finished);
+        assert_eq!((opener.team_h, opener.team_h_score), (13, Some(1)));
+        assert_eq!(opener.stats[0].identifier, "goals_scored");
+        assert_eq!(opener.stats[0].h[0].element, 355);
+
+        let history = season.player_history(20);
+        assert_eq!(history.len(), 1);
+        assert_eq!((history[0].goals_scored, history[0].bonus, history[0].total_points), (1, 2, 9));
+        assert!(!history[0].was_home);
+        assert_eq!(history[0].expected_goals, 0.4);
+        assert_eq!(season.gameweek(1).len(), 4);
+
+        // The older season has fewer columns, which are noted rather than failing the import
+        let older = archive.season("2022-23").unwrap();
+        assert_eq!(older.player_history(318)[0].goals_scored, 2);
+        assert_eq!(older.player_history(318)[0].starts, 0);
+        let players = older.drift.record("player").unwrap();
+        assert!(players.vanished.contains("expected_goals"));
+        assert!(players.changed_type.is_empty());
+        assert!(older.drift.record("player gameweek").unwrap().vanished.contains("starts"));
+
+        assert_eq!(
+            fpl_archive::python_literal("[{'a': None, 'b': True, 'c': 'it\\'s'}]"),
+            Some(serde_json::json!([{"a": null, "b": true, "c": "it's"}]))
+        );
+        assert!(matches!(
+            fpl_archive::ArchivedSeason::load(env!("CARGO_MANIFEST_DIR"), "2019-20"),
+            Err(fpl_data::FplDataError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_read_records_reports_the_bad_row() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("teams.csv");
+        std::fs::write(&path, "id,name\n1,Arsenal\n2,Aston Villa,extra\n").unwrap();
+
+        let mut drift = fpl_schema::DriftReport::default();
+        let result: Result<Vec<fpl_data::FplApiTeam>, _> =
+            fpl_archive::read_records("team", &path, &mut drift);
+        assert!(matches!(
+            result,
+            Err(fpl_data::FplDataError::Csv { line: Some(3), .. })
+        ));
+    }
+
+    #[test]
+    fn test_bootstrap_static_tolerates_schema_drift() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../test_data/bootstrap-static.json")).unwrap();
+        let haaland = &mut json["elements"][1];
+        haaland["now_cost"] = serde_json::Value::from("14.5");
+        haaland["bps"] = serde_json::Value::from(-4_000_000_000i64);
+        haaland["expected_saves"] = serde_json::Value::from("0.00");
+        haaland.as_object_mut().unwrap().remove("form");
+        json["teams"][0].as_object_mut().unwrap().remove("pulse_id");
+
+        let bootstrap = fpl_data::BootstrapStatic::from_json(json).unwrap();
+
+        let haaland = bootstrap.get_player(351).unwrap();
+        assert_eq!(haaland.web_name, "Haaland");
+        assert_eq!(haaland.now_cost, 0);
+        assert_eq!(haaland.form, None);
+        assert_eq!(haaland.unknown_fields["expected_saves"], "0.00");
+
+        let players = bootstrap.drift.record("player").unwrap();
+        assert_eq!(players.records_affected, 2);
+        assert!(players.appeared.contains("expected_saves"));
+        assert!(players.vanished.contains("form"));
+        assert_eq!(
+            players.changed_type["now_cost"],
+            (fpl_schema::JsonType::Number, fpl_schema::JsonType::String)
+        );
+        assert!(players.changed_type.contains_key("bps"));
+        assert!(bootstrap.drift.record("team").unwrap().vanished.contains("pulse_id"));
+        assert!(bootstrap.drift.record("position").is_none());
+        assert!(bootstrap
+            .drift
+            .to_string()
+            .contains("changed type: now_cost (number -> string)"));
+
+        // The recorded data already carries fields the structs do not declare
+        let clean: serde_json::Value =
+            serde_json::from_str(include_str!("../test_data/bootstrap-static.json")).unwrap();
+        let clean_drift = fpl_data::BootstrapStatic::from_json(clean).unwrap().drift;
+        let players = clean_drift.record("player").unwrap();
+        assert!(players.appeared.contains("can_transact"));
+        assert!(players.vanished.is_empty() && players.changed_type.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_collects_drift_from_every_endpoint() {
+        let fixtures = serde_json::json!([{
+            "code": 1, "event": 1, "finished": false, "finished_provisional": false, "id": 1,
+            "kickoff_time": null, "minutes": 0, "provisional_start_time": false, "started": false,
+            "team_a": 2, "team_a_score": null, "team_h": 1, "team_h_score": null, "stats": [],
+            "pulse_id": 115827
+        }]);
+        let mut live: serde_json::Value =
+            serde_json::from_str(include_str!("../test_data/event-1-live.json")).unwrap();
+        live["elements"][0]["stats"]["bps"] = serde_json::Value::from("n/a");
+        live["elements"][0]["explain"][0]["stats"][0]["element_type"] = serde_json::Value::from(2);
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("bootstrap-static/", include_str!("../test_data/bootstrap-static.json"))
+            .with_json("fixtures/", &fixtures.to_string())
+            .with_json("event/1/live/", &live.to_string())
+            .with_json("entry/1/", include_str!("../test_data/entry-1.json"));
+        let client = fpl_client::FplClient::new().with_transport(transport);
+
+        client.get_players().await.unwrap();
+        assert!(client.drift().record("player").unwrap().appeared.contains("can_transact"));
+
+        // A postponed fixture has no gameweek or kickoff time. Clones of a client add to the
+        // same report.
+        let fixtures = client.clone().get_fixtures().await.unwrap();
+        assert_eq!((fixtures[0].event, fixtures[0].kickoff_time.as_deref()), (Some(1), None));
+        assert_eq!(fixtures[0].unknown_fields["pulse_id"], 115827);
+
+        let drift = client.drift();
+        let fixture_drift = drift.record("fixture").unwrap();
+        assert!(fixture_drift.changed_type.is_empty());
+        assert!(fixture_drift.appeared.contains("pulse_id"));
+
+        // The query endpoints report drift too, and a bad value in a nested record only
+        // resets that value rather than the whole record around it
+        let live = client.get_live_gameweek(1).await.unwrap();
+        assert_eq!((live.elements[0].stats.bps, live.elements[0].stats.minutes), (0, 180));
+        assert!(!live.elements[0].explain[0].stats.is_empty());
+        let drift = client.drift();
+        let stats_drift = drift.record("live stats").unwrap();
+        assert_eq!(
+            stats_drift.changed_type["bps"],
+            (fpl_schema::JsonType::Number, fpl_schema::JsonType::String)
+        );
+        assert!(drift.record("live explain stat").unwrap().appeared.contains("element_type"));
+        assert!(drift.record("live element").is_none());
+
+        client.get_entry(1).await.unwrap();
+        assert!(client.drift().record("entry").unwrap().appeared.contains("years_active"));
+
+        client.clear_drift();
+        assert!(client.drift().is_empty());
+    }
+
+    #[test]
+    fn test_decimal_strings() {
+        let bootstrap = fpl_data::BootstrapStatic::from_json(
+            serde_json::from_str(include_str!("../test_data/bootstrap-static.json")).unwrap(),
+        )
+        .unwrap();
+        let gabriel = bootstrap.get_player(3).unwrap();
+        assert_eq!(gabriel.selected_by_percent, Some(31.4));
+        assert_eq!(gabriel.expected_goals_conceded, Some(11.31));
+        assert_eq!(gabriel.ep_next, Some(4.5));
+
+        let mut players: serde_json::Value = serde_json::to_value(bootstrap.players()).unwrap();
+        assert_eq!(players[0]["form"], "5");
+        players[0]["form"] = serde_json::Value::from("");
+        players[0]["ep_next"] = serde_json::Value::from("");
+        players[0]["ep_this"] = serde_json::Value::Null;
+        players[0]["threat"] = serde_json::Value::from(12.5);
+        players[1]["ict_index"] = serde_json::Value::from("n/a");
+
+        let error = fpl_data::convert_records::<fpl_data::FplApiPlayer>("player", players.clone())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to convert player 351 (field ict_index): invalid decimal string \"n/a\""
+        );
+
+        players[1]["ict_index"] = serde_json::Value::from("96.8");
+        let strict =
+            fpl_data::convert_records::<fpl_data::FplApiPlayer>("player", players.clone()).unwrap();
+        assert_eq!(strict[0].form, None);
+        assert_eq!(strict[0].ep_next, None);
+        assert_eq!(strict[0].ep_this, None);
+        assert_eq!(strict[0].threat, Some(12.5));
+
+        // The tolerant parse keeps a decimal sent as a plain number rather than zeroing it
+        players[1]["selected_by_percent"] = serde_json::Value::from(62.3);
+        let mut drift = fpl_schema::DriftReport::default();
+        let tolerant: Vec<fpl_data::FplApiPlayer> =
+            fpl_schema::convert_records_tolerant("player", players, &mut drift).unwrap();
+        assert_eq!(tolerant[0].threat, Some(12.5));
+        assert_eq!(tolerant[1].selected_by_percent, Some(62.3));
+        assert_eq!(tolerant[1].ict_index, Some(96.8));
+        assert!(drift.record("player").unwrap().changed_type.is_empty());
+
+        // Fields that always carry a value reject an empty string rather than reading it as zero
+        let mut summary: serde_json::Value =
+            serde_json::from_str(include_str!("../test_data/element-summary-351.json")).unwrap();
+        summary["history"][0]["expected_goals"] = serde_json::Value::from("");
+        let error = fpl_data::convert_records::<fpl_data::FplApiPlayerHistory>(
+            "player history",
+            summary["history"].take(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("empty decimal string"), "{}", error);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_blocking_client() {
+        let transport = fpl_client::InMemoryTransport::new()
+            .with_json("bootstrap-static/", include_str!("../test_data/bootstrap-static.json"))
+            .with_json("element-summary/351/", include_str!("../test_data/element-summary-351.json"));
+        let client = super::blocking::FplClient::from_async(
+            fpl_client::FplClient::new().with_transport(transport),
+        )
+        .unwrap();
+
+        assert_eq!(client.get_players().unwrap().len(), 2);
+        assert_eq!(client.get_bootstrap_static().unwrap().teams().len(), 2);
+        assert!(!client.get_element_summary(351).unwrap().history.is_empty());
+        assert_eq!(
+            client.get_fixtures().unwrap_err(),
+            fpl_data::FplDataError::HttpStatus {
+                url: client.client().endpoint_url("fixtures/"),
+                status: 404
+            }
+        );
+
+        // The free functions reuse a single client and runtime
+        assert!(std::ptr::eq(
+            super::blocking::default_client().unwrap(),
+            super::blocking::default_client().unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_server_advances_gameweeks() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(
+            directory.path().join("bootstrap-static.json"),
+            include_str!("../test_data/bootstrap-static.json"),
+        )
+        .unwrap();
+        std::fs::write(directory.path().join("fixtures.json"), "[]").unwrap();
+        std::fs::write(
+            directory.path().join("element-summary_351.json"),
+            include_str!("../test_data/element-summary-351.json"),
+        )
+        .unwrap();
+        std::fs::create_dir(directory.path().join("gameweek-2")).unwrap();
+        std::fs::write(directory.path().join("gameweek-2").join("fixtures.json"), "not json").unwrap();
+
+        let snapshots = fpl_mock_server::MockSnapshots::new(directory.path());
+        assert_eq!(snapshots.gameweek(), 1);
+        let server = fpl_mock_server::MockServer::start(snapshots, "127.0.0.1:0")
+            .await
+            .unwrap();
+        let client = server.client();
+
+        let current = |events: &[fpl_data::FplApiGameweek]| {
+            events.iter().find(|event| event.is_current).map(|event| event.id)
+        };
+        assert_eq!(current(&client.get_events().await.unwrap()), Some(1));
+        assert!(client.get_fixtures().await.unwrap().is_empty());
+        assert!(client.get_element_summary(351).await.is_ok());
+        assert!(matches!(
+            client.get_live_gameweek(1).await,
+            Err(fpl_data::FplDataError::HttpStatus { status: 404, .. })
+        ));
+
+        let advanced = reqwest::Client::new()
+            .post(format!("http://{}/mock/advance", server.address()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(advanced.text().await.unwrap(), "{\"gameweek\": 2}");
+
+        let events = client.get_events().await.unwrap();
+        assert_eq!(current(&events), Some(2));
+        assert!(events[0].finished && events[0].is_previous);
+        assert!(matches!(
+            client.get_fixtures().await,
+            Err(fpl_data::FplDataError::JsonShape { .. })
+        ));
+
+        // An address that cannot be listened on is a server error, not a file error
+        let taken = server.address().to_string();
+        let error = fpl_mock_server::MockServer::start(
+            fpl_mock_server::MockSnapshots::new(directory.path()),
+            &taken,
+        )
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(error, fpl_data::FplDataError::Server { address, .. } if address == taken));
+
+        assert_eq!(fpl_mock_server::reason_phrase(429), "Too Many Requests");
+        assert_eq!(fpl_mock_server::reason_phrase(418), "Client Error");
+        assert_eq!(fpl_mock_server::reason_phrase(599), "Server Error");
     }
 }
@@ -0,0 +1,321 @@
+# Generates the synthetic 2024/25 season in this directory. See README.md.
+#
+#     python3 fpl_data/test_data/synthetic-2024-25/generate.py
+#
+# The output only depends on the seed, so rerunning it leaves the files unchanged.
+import json, random, copy, datetime, os
+random.seed(2425)
+HERE = os.path.dirname(os.path.abspath(__file__))
+base = json.load(open(os.path.join(HERE, '..', 'bootstrap-static.json')))
+player_t = base['elements'][0]
+team_t = base['teams'][0]
+event_t = base['events'][1]
+
+teams = [("Arsenal","ARS",3),("Aston Villa","AVL",7),("Bournemouth","BOU",91),("Brentford","BRE",94),("Brighton","BHA",36),
+ ("Chelsea","CHE",8),("Crystal Palace","CRY",31),("Everton","EVE",11),("Fulham","FUL",54),("Ipswich","IPS",40),
+ ("Leicester","LEI",13),("Liverpool","LIV",14),("Man City","MCI",43),("Man Utd","MUN",1),("Newcastle","NEW",4),
+ ("Nott'm Forest","NFO",17),("Southampton","SOU",20),("Spurs","TOT",6),("West Ham","WHU",21),("Wolves","WOL",39)]
+strength = [5,4,3,3,3,4,3,3,3,2,2,5,5,3,4,3,2,4,3,3]
+squads = [
+ [("David","Raya Martin","Raya",1),("Bukayo","Saka","Saka",3),("Gabriel","dos Santos Magalhães","Gabriel",2)],
+ [("Emiliano","Martínez Romero","Martinez",1),("Ollie","Watkins","Watkins",4),("John","McGinn","McGinn",3)],
+ [("Kepa","Arrizabalaga Revuelta","Kepa",1),("Antoine","Semenyo","Semenyo",3),("Evanilson","Francisco Evanilson de Lima Barbosa","Evanilson",4)],
+ [("Mark","Flekken","Flekken",1),("Bryan","Mbeumo","Mbeumo",3),("Yoane","Wissa","Wissa",4)],
+ [("Bart","Verbruggen","Verbruggen",1),("Kaoru","Mitoma","Mitoma",3),("Danny","Welbeck","Welbeck",4)],
+ [("Robert","Lynch Sánchez","Sánchez",1),("Cole","Palmer","Palmer",3),("Nicolas","Jackson","N.Jackson",4)],
+ [("Dean","Henderson","Henderson",1),("Eberechi","Eze","Eze",3),("Jean-Philippe","Mateta","Mateta",4)],
+ [("Jordan","Pickford","Pickford",1),("James","Tarkowski","Tarkowski",2),("Dominic","Calvert-Lewin","Calvert-Lewin",4)],
+ [("Bernd","Leno","Leno",1),("Alex","Iwobi","Iwobi",3),("Raúl","Jiménez","Raúl",4)],
+ [("Arijanet","Muric","Muric",1),("Omari","Hutchinson","Hutchinson",3),("Liam","Delap","Delap",4)],
+ [("Mads","Hermansen","Hermansen",1),("Wout","Faes","Faes",2),("Jamie","Vardy","Vardy",4)],
+ [("Alisson","Becker","Alisson",1),("Mohamed","Salah","M.Salah",3),("Trent","Alexander-Arnold","Alexander-Arnold",2)],
+ [("Ederson","Santana de Moraes","Ederson M.",1),("Erling","Haaland","Haaland",4),("Phil","Foden","Foden",3)],
+ [("André","Onana","Onana",1),("Bruno","Borges Fernandes","B.Fernandes",3),("Rasmus","Højlund","Højlund",4)],
+ [("Nick","Pope","Pope",1),("Alexander","Isak","Isak",4),("Anthony","Gordon","Gordon",3)],
+ [("Matz","Sels","Sels",1),("Chris","Wood","Wood",4),("Murillo","Santiago Costa dos Santos","Murillo",2)],
+ [("Aaron","Ramsdale","Ramsdale",1),("Adam","Armstrong","A.Armstrong",3),("Kyle","Walker-Peters","Walker-Peters",2)],
+ [("Guglielmo","Vicario","Vicario",1),("Heung-Min","Son","Son",3),("Dominic","Solanke-Mitchell","Solanke",4)],
+ [("Alphonse","Areola","Areola",1),("Jarrod","Bowen","Bowen",3),("Michail","Antonio","Antonio",4)],
+ [("José","Malheiro de Sá","José Sá",1),("Matheus","Santos Carneiro Da Cunha","Cunha",4),("Rayan","Aït-Nouri","Aït-Nouri",2)],
+]
+
+# players
+players = []
+pid = 0
+for t, squad in enumerate(squads, start=1):
+    for first, second, web, pos in squad:
+        pid += 1
+        # keep the ids the older fixtures use for Saka/Gabriel and Haaland
+        if web == "Saka": i = 2
+        elif web == "Gabriel": i = 3
+        elif web == "Haaland": i = 351
+        else: i = pid
+        players.append(dict(id=i, first=first, second=second, web=web, pos=pos, team=t))
+ids = sorted(p['id'] for p in players)
+assert len(set(ids)) == len(ids)
+
+# round robin, 38 rounds
+order = list(range(1, 21))
+rounds = []
+arr = order[:]
+for r in range(19):
+    pairs = []
+    for k in range(10):
+        a, b = arr[k], arr[19-k]
+        pairs.append((a, b) if (r + k) % 2 == 0 else (b, a))
+    rounds.append(pairs)
+    arr = [arr[0]] + [arr[-1]] + arr[1:-1]
+rounds += [[(b, a) for a, b in rnd] for rnd in rounds]
+# Put Man Utd v Fulham first, like the real opening game
+for ri, rnd in enumerate(rounds):
+    if (14, 9) in rnd:
+        break
+rounds[0], rounds[ri] = rounds[ri], rounds[0]
+rounds[0].remove((14, 9)); rounds[0].insert(0, (14, 9))
+
+start = datetime.datetime(2024, 8, 16, 17, 30, tzinfo=datetime.timezone.utc)
+fmt = lambda d: d.strftime('%Y-%m-%dT%H:%M:%SZ')
+FINISHED_EVENTS = 2
+# Only the finished gameweeks and the next one are written out
+KEPT_EVENTS = FINISHED_EVENTS + 1
+fixtures = []
+totals = {p['id']: dict(minutes=0, goals_scored=0, assists=0, clean_sheets=0, goals_conceded=0, own_goals=0,
+                        penalties_saved=0, penalties_missed=0, yellow_cards=0, red_cards=0, saves=0, bonus=0, bps=0,
+                        starts=0, total_points=0, event_points=0) for p in players}
+by_team = {t: [p for p in players if p['team'] == t] for t in range(1, 21)}
+goal_pts = {1: 10, 2: 6, 3: 5, 4: 4}
+cs_pts = {1: 4, 2: 4, 3: 1, 4: 0}
+fid = 0
+# per gameweek, player -> (fixture, stats, explain) for the live files
+live = {ev: {} for ev in range(1, FINISHED_EVENTS + 1)}
+for ev, rnd in enumerate(rounds, start=1):
+    deadline = start + datetime.timedelta(days=7 * (ev - 1)) - datetime.timedelta(minutes=90)
+    for k, (h, a) in enumerate(rnd):
+        fid += 1
+        kickoff = deadline + datetime.timedelta(minutes=90) + datetime.timedelta(hours=(0 if k == 0 else 20 + 3 * (k % 4)))
+        fin = ev <= FINISHED_EVENTS
+        f = dict(code=2444469 + fid, event=ev, finished=fin, finished_provisional=fin, id=fid, kickoff_time=fmt(kickoff),
+                 minutes=90 if fin else 0, provisional_start_time=False, started=fin, team_a=a,
+                 team_a_score=None, team_h=h, team_h_score=None, stats=[], team_h_difficulty=strength[a-1],
+                 team_a_difficulty=strength[h-1], pulse_id=115827 + fid)
+        if fin:
+            score = {}
+            stat = {k2: {'h': [], 'a': []} for k2 in ['goals_scored','assists','own_goals','penalties_saved','penalties_missed','yellow_cards','red_cards','saves','bonus','bps']}
+            bps = {}
+            for side, team, opp in [('h', h, a), ('a', a, h)]:
+                n = max(0, min(4, int(random.gauss(1.4 + 0.3 * (strength[team-1] - strength[opp-1]), 1.0))))
+                score[side] = n
+            for side, team, opp in [('h', h, a), ('a', a, h)]:
+                conceded = score['a' if side == 'h' else 'h']
+                squad = by_team[team]
+                outfield = [p for p in squad if p['pos'] != 1]
+                pts = {}
+                line = {}
+                for p in squad:
+                    line[p['id']] = dict(minutes=90, goals_scored=0, assists=0, clean_sheets=int(conceded == 0),
+                                         goals_conceded=conceded, saves=0, yellow_cards=0, bonus=0, bps=0)
+                    t = totals[p['id']]
+                    t['minutes'] += 90; t['starts'] += 1; t['goals_conceded'] += conceded
+                    pts[p['id']] = 2
+                    bps[p['id']] = (side, 3)
+                    if conceded == 0:
+                        t['clean_sheets'] += 1
+                        pts[p['id']] += cs_pts[p['pos']]
+                        bps[p['id']] = (side, bps[p['id']][1] + (12 if p['pos'] <= 2 else 0))
+                    elif p['pos'] <= 2:
+                        pts[p['id']] -= conceded // 2
+                goals = {}
+                assists = {}
+                for g in range(score[side]):
+                    scorer = random.choice(outfield + [p for p in outfield if p['pos'] == 4])
+                    goals[scorer['id']] = goals.get(scorer['id'], 0) + 1
+                    others = [p for p in outfield if p['id'] != scorer['id']]
+                    if others and random.random() < 0.7:
+                        helper = random.choice(others)
+                        assists[helper['id']] = assists.get(helper['id'], 0) + 1
+                for i2, n in goals.items():
+                    pos = next(p['pos'] for p in squad if p['id'] == i2)
+                    stat['goals_scored'][side].append(dict(value=n, element=i2))
+                    totals[i2]['goals_scored'] += n
+                    line[i2]['goals_scored'] = n
+                    pts[i2] += goal_pts[pos] * n
+                    bps[i2] = (side, bps[i2][1] + {2: 12, 3: 18, 4: 24}.get(pos, 12) * n)
+                for i2, n in assists.items():
+                    stat['assists'][side].append(dict(value=n, element=i2))
+                    totals[i2]['assists'] += n
+                    line[i2]['assists'] = n
+                    pts[i2] += 3 * n
+                    bps[i2] = (side, bps[i2][1] + 9 * n)
+                gk = squad[0]
+                saves = random.randint(0, 6)
+                if saves:
+                    stat['saves'][side].append(dict(value=saves, element=gk['id']))
+                    totals[gk['id']]['saves'] += saves
+                    line[gk['id']]['saves'] = saves
+                    pts[gk['id']] += saves // 3
+                    bps[gk['id']] = (side, bps[gk['id']][1] + 2 * saves)
+                if random.random() < 0.5:
+                    booked = random.choice(outfield)
+                    stat['yellow_cards'][side].append(dict(value=1, element=booked['id']))
+                    totals[booked['id']]['yellow_cards'] += 1
+                    line[booked['id']]['yellow_cards'] = 1
+                    pts[booked['id']] -= 1
+                    bps[booked['id']] = (side, bps[booked['id']][1] - 3)
+                for i2, v in pts.items():
+                    totals[i2]['total_points'] += v
+                    live[ev][i2] = dict(fixture=fid, line=line[i2], points=v)
+                    if ev == FINISHED_EVENTS:
+                        totals[i2]['event_points'] += v
+            ranked = sorted(bps.items(), key=lambda kv: -kv[1][1])
+            for i2, (side, b) in ranked:
+                # Tied players share the higher place, and the places they take are skipped
+                rank = 1 + sum(1 for _, (_, other) in ranked if other > b)
+                if rank > 3:
+                    break
+                bonus = 4 - rank
+                live[ev][i2]['line']['bonus'] = bonus
+                live[ev][i2]['points'] += bonus
+                stat['bonus'][side].append(dict(value=bonus, element=i2))
+                totals[i2]['bonus'] += bonus
+                totals[i2]['total_points'] += bonus
+                if ev == FINISHED_EVENTS:
+                    totals[i2]['event_points'] += bonus
+            for i2, (side, b) in sorted(bps.items(), key=lambda kv: -kv[1][1]):
+                stat['bps'][side].append(dict(value=b, element=i2))
+                totals[i2]['bps'] += b
+                live[ev][i2]['line']['bps'] = b
+            f['team_h_score'] = score['h']; f['team_a_score'] = score['a']
+            f['stats'] = [dict(identifier=k2, a=v['a'], h=v['h']) for k2, v in stat.items()]
+        fixtures.append(f)
+assert fixtures[0]['team_h'] == 14 and len(fixtures) == 380
+fixtures = [f for f in fixtures if f['event'] <= KEPT_EVENTS]
+
+# events
+events = []
+for ev in range(1, 39):
+    e = copy.deepcopy(event_t)
+    deadline = start + datetime.timedelta(days=7 * (ev - 1)) - datetime.timedelta(minutes=90)
+    fin = ev <= FINISHED_EVENTS
+    e.update(id=ev, name=f"Gameweek {ev}", deadline_time=fmt(deadline), deadline_time_epoch=int(deadline.timestamp()),
+             finished=fin, data_checked=fin, is_previous=(ev == FINISHED_EVENTS - 1), is_current=(ev == FINISHED_EVENTS),
+             is_next=(ev == FINISHED_EVENTS + 1), average_entry_score=(57 if ev == 1 else 49) if fin else 0,
+             highest_score=(127 if ev == 1 else 112) if fin else None, highest_scoring_entry=(3383 if ev == 1 else 81944) if fin else None,
+             ranked_count=9000000 if fin else 0, transfers_made=(0 if ev == 1 else 7200000) if fin else 0,
+             chip_plays=[{"chip_name": "bboost", "num_played": 140000}, {"chip_name": "3xc", "num_played": 210000}] if fin else [])
+    if fin:
+        top = max((p for p in players), key=lambda p: 0)  # replaced below
+    e.update(most_selected=None, most_transferred_in=None, top_element=None, top_element_info=None,
+             most_captained=None, most_vice_captained=None)
+    if fin:
+        e.update(most_selected=351, most_transferred_in=2, most_captained=351, most_vice_captained=2)
+    events.append(e)
+
+# players out
+def r2(x): return round(x, 2)
+elements = []
+for p in sorted(players, key=lambda p: p['id']):
+    t = totals[p['id']]
+    e = copy.deepcopy(player_t)
+    team = teams[p['team']-1]
+    mins = t['minutes']; nineties = mins / 90 if mins else 0
+    per90 = lambda v: r2(v / nineties) if nineties else 0.0
+    cost = {1: 50, 2: 55, 3: 80, 4: 85}[p['pos']] + (20 if strength[p['team']-1] == 5 else 0)
+    if p['web'] in ("Haaland", "M.Salah"): cost = 150 if p['web'] == "Haaland" else 125
+    xg = r2(t['goals_scored'] * 0.8 + 0.1 * (p['pos'] - 1)); xa = r2(t['assists'] * 0.7 + 0.05)
+    xgc = r2(t['goals_conceded'] * 0.9)
+    e.update(id=p['id'], code=100000 + p['id'] * 7, first_name=p['first'], second_name=p['second'], web_name=p['web'],
+             element_type=p['pos'], team=p['team'], team_code=team[2], now_cost=cost,
+             photo=f"{100000 + p['id'] * 7}.jpg", minutes=mins, starts=t['starts'],
+             goals_scored=t['goals_scored'], assists=t['assists'], clean_sheets=t['clean_sheets'],
+             goals_conceded=t['goals_conceded'], own_goals=0, penalties_saved=0, penalties_missed=0,
+             yellow_cards=t['yellow_cards'], red_cards=0, saves=t['saves'], bonus=t['bonus'], bps=t['bps'],
+             total_points=t['total_points'], event_points=t['event_points'],
+             form=f"{t['total_points'] / 2:.1f}", points_per_game=f"{t['total_points'] / 2:.1f}",
+             value_form=f"{t['total_points'] / 2 / (cost / 10):.1f}", value_season=f"{t['total_points'] / (cost / 10):.1f}",
+             expected_goals=f"{xg:.2f}", expected_assists=f"{xa:.2f}", expected_goal_involvements=f"{xg + xa:.2f}",
+             expected_goals_conceded=f"{xgc:.2f}",
+             expected_goals_per_90=per90(xg), expected_assists_per_90=per90(xa), expected_goal_involvements_per_90=per90(xg + xa),
+             expected_goals_conceded_per_90=per90(xgc), goals_conceded_per_90=per90(t['goals_conceded']),
+             saves_per_90=per90(t['saves']), starts_per_90=per90(t['starts']), clean_sheets_per_90=per90(t['clean_sheets']),
+             selected_by_percent=f"{random.uniform(0.5, 60):.1f}",
+             transfers_in=random.randint(1000, 3000000), transfers_out=random.randint(1000, 2000000),
+             transfers_in_event=random.randint(100, 300000), transfers_out_event=random.randint(100, 300000),
+             ep_next=f"{random.uniform(1, 8):.1f}", ep_this=f"{random.uniform(1, 8):.1f}")
+    # A rough ICT index from the player's own returns, rather than one value for everyone
+    influence = t['bps'] * 1.5 + t['saves'] * 2
+    creativity = t['assists'] * 25 + 5 * (p['pos'] - 1)
+    threat = t['goals_scored'] * 30 + xg * 20
+    e.update(influence=f"{influence:.1f}", creativity=f"{creativity:.1f}", threat=f"{threat:.1f}",
+             ict_index=f"{(influence + creativity + threat) / 10:.1f}")
+    elements.append(e)
+# Ranks over the whole list, 1 for the highest value
+for key, rank_key in [('influence', 'influence_rank'), ('creativity', 'creativity_rank'), ('threat', 'threat_rank'),
+                      ('ict_index', 'ict_index_rank'), ('now_cost', 'now_cost_rank'), ('form', 'form_rank'),
+                      ('points_per_game', 'points_per_game_rank'), ('selected_by_percent', 'selected_rank')]:
+    ordered = sorted(elements, key=lambda e: -float(e[key]))
+    for rank, e in enumerate(ordered, start=1):
+        e[rank_key] = rank
+top = max(elements, key=lambda e: e['total_points'])
+for e in events[:FINISHED_EVENTS]:
+    e['top_element'] = top['id']; e['top_element_info'] = {"id": top['id'], "points": top['event_points']}
+
+team_out = []
+for i, (name, short, code) in enumerate(teams, start=1):
+    tm = copy.deepcopy(team_t)
+    s = strength[i-1]
+    tm.update(id=i, name=name, short_name=short, code=code, pulse_id=[1,2,127,130,131,4,6,7,34,8,26,10,11,12,23,15,20,21,25,38][i-1],
+              strength=s, strength_overall_home=1000 + 70 * s, strength_overall_away=1000 + 70 * s + 10,
+              strength_attack_home=1000 + 65 * s, strength_attack_away=1000 + 65 * s + 20,
+              strength_defence_home=1000 + 68 * s, strength_defence_away=1000 + 68 * s + 15,
+              played=0, win=0, draw=0, loss=0, points=0, position=0)
+    team_out.append(tm)
+for et in base['element_types']:
+    et['element_count'] = sum(1 for p in players if p['pos'] == et['id'])
+
+boot = copy.deepcopy(base)
+boot.update(events=events, teams=team_out, elements=elements, total_players=11468412)
+json.dump(boot, open(os.path.join(HERE, 'bootstrap-static.json'), 'w'), indent=1, ensure_ascii=False)
+json.dump(fixtures, open(os.path.join(HERE, 'fixtures.json'), 'w'), indent=1, ensure_ascii=False)
+# event/{id}/live for the finished gameweeks; everyone plays once a gameweek
+stat_points = dict(goals_scored=goal_pts, assists={k: 3 for k in goal_pts}, clean_sheets=cs_pts, saves=None,
+                   yellow_cards={k: -1 for k in goal_pts}, bonus={k: 1 for k in goal_pts})
+pos_of = {p['id']: p['pos'] for p in players}
+for ev in range(1, FINISHED_EVENTS + 1):
+    live_elements = []
+    for i2 in sorted(live[ev]):
+        entry = live[ev][i2]
+        ln = entry['line']; pos = pos_of[i2]
+        explain = [dict(identifier='minutes', points=2, value=90, points_modification=0)]
+        for key in ['goals_scored', 'assists', 'clean_sheets', 'goals_conceded', 'saves', 'yellow_cards', 'bonus']:
+            v = ln[key]
+            if not v:
+                continue
+            if key == 'goals_conceded':
+                pts_v = -(v // 2) if pos <= 2 else 0
+            elif key == 'saves':
+                pts_v = v // 3
+            else:
+                pts_v = stat_points[key][pos] * v
+            explain.append(dict(identifier=key, points=pts_v, value=v, points_modification=0))
+        assert sum(x['points'] for x in explain) == entry['points'], (ev, i2)
+        stats = dict(minutes=90, goals_scored=ln['goals_scored'], assists=ln['assists'], clean_sheets=ln['clean_sheets'],
+                     goals_conceded=ln['goals_conceded'], own_goals=0, penalties_saved=0, penalties_missed=0,
+                     yellow_cards=ln['yellow_cards'], red_cards=0, saves=ln['saves'], bonus=ln['bonus'], bps=ln['bps'],
+                     influence="0.0", creativity="0.0", threat="0.0", ict_index="0.0", starts=1,
+                     expected_goals="0.00", expected_assists="0.00", expected_goal_involvements="0.00",
+                     expected_goals_conceded="0.00", total_points=entry['points'], in_dreamteam=False)
+        live_elements.append(dict(id=i2, stats=stats, explain=[dict(fixture=entry['fixture'], stats=explain)], modified=False))
+    json.dump(dict(elements=live_elements), open(os.path.join(HERE, f'event_{ev}_live.json'), 'w'), indent=1, ensure_ascii=False)
+
+# The files must agree with each other: season totals are the sum of the gameweek points,
+# and the bonus in the fixtures is the bonus in the live explain
+for e in elements:
+    assert e['total_points'] == sum(live[ev][e['id']]['points'] for ev in live), e['web_name']
+    assert e['bonus'] == sum(live[ev][e['id']]['line']['bonus'] for ev in live), e['web_name']
+for f in fixtures:
+    for s in f['stats']:
+        if s['identifier'] == 'bonus':
+            for side in ('h', 'a'):
+                for entry in s[side]:
+                    assert live[f['event']][entry['element']]['line']['bonus'] == entry['value'], f['id']
+print(len(elements), top['web_name'], top['total_points'], sum(1 for f in fixtures if f['finished']))
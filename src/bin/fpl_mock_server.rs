@@ -0,0 +1,60 @@
+// Serves a directory of FPL API snapshots on localhost, for running demos and tests offline.
+//
+//     cargo run --bin fpl_mock_server -- <snapshot directory> [--port 8080] [--gameweek 1]
+//
+// Point a client at it with `FplClient::new().with_base_url("http://127.0.0.1:8080/api/")`,
+// and move the season on with `curl -X POST http://127.0.0.1:8080/mock/advance`.
+use std::process::exit;
+
+use fpl_data::fpl_mock_server::{MockServer, MockSnapshots};
+
+fn usage() -> ! {
+    eprintln!("Usage: fpl_mock_server <snapshot directory> [--port <port>] [--gameweek <gameweek>]");
+    exit(2);
+}
+
+#[tokio::main]
+async fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut directory = None;
+    let mut port = 8080u16;
+    let mut gameweek = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => port = args.next().and_then(|p| p.parse().ok()).unwrap_or_else(|| usage()),
+            "--gameweek" => {
+                gameweek = Some(args.next().and_then(|g| g.parse().ok()).unwrap_or_else(|| usage()))
+            }
+            "-h" | "--help" => usage(),
+            _ if directory.is_none() => directory = Some(arg),
+            _ => usage(),
+        }
+    }
+
+    let directory = directory.unwrap_or_else(|| usage());
+    let mut snapshots = MockSnapshots::new(&directory);
+    if let Some(gameweek) = gameweek {
+        snapshots = snapshots.with_gameweek(gameweek);
+    }
+    let gameweek = snapshots.gameweek();
+
+    let server = match MockServer::start(snapshots, &format!("127.0.0.1:{}", port)).await {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("Failed to start the mock server: {}", error);
+            exit(1);
+        }
+    };
+    println!(
+        "Serving {} at {} from gameweek {}",
+        directory,
+        server.base_url(),
+        gameweek
+    );
+
+    tokio::select! {
+        _ = server.wait() => (),
+        _ = tokio::signal::ctrl_c() => (),
+    }
+}